@@ -6,406 +6,414 @@
 # Regular strings
 
 # Profile about/bio field label
-About_00c0 = Über mich
+About_00c04c = Über mich
 # Column title for account management
-Accounts_f018 = Konten
+Accounts_f01844 = Konten
 # Button label to add a relay
-Add_269d = Hinzufügen
+Add_269d81 = Hinzufügen
 # Label for add column button
-Add_47df = Hinzufügen
+Add_47dfb8 = Hinzufügen
 # Button label to add a different wallet
-Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d = Eine andere Wallet hinzufügen, die nur für dieses Konto verwendet wird
+Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d48 = Eine andere Wallet hinzufügen, die nur für dieses Konto verwendet wird
 # Error message for missing wallet
-Add_a_wallet_to_continue_d170 = Wallet hinzufügen um fortzufahren
+Add_a_wallet_to_continue_d170b3 = Wallet hinzufügen um fortzufahren
 # Button label to add a new account
-Add_account_1cfc = Konto hinzufügen
+Add_account_1cfc87 = Konto hinzufügen
 # Column title for adding new account
-Add_Account_d06c = Konto hinzufügen
+Add_Account_d06c6a = Konto hinzufügen
 # Column title for adding algorithm column
-Add_Algo_Column_0d75 = Algorithmus-Spalte hinzufügen
+Add_Algo_Column_0d751c = Algorithmus-Spalte hinzufügen
 # Column title for adding new column
-Add_Column_c764 = Spalte hinzufügen
+Add_Column_c764c5 = Spalte hinzufügen
 # Column title for adding new deck
-Add_Deck_fabf = Deck hinzufügen
+Add_Deck_fabf9d = Deck hinzufügen
 # Column title for adding external notifications column
-Add_External_Notifications_Column_41ae = Externe Benachrichtigungsspalte hinzufügen
+Add_External_Notifications_Column_41aee7 = Externe Benachrichtigungsspalte hinzufügen
 # Column title for adding hashtag column
-Add_Hashtag_Column_ebf4 = Hashtag-Spalte hinzufügen
+Add_Hashtag_Column_ebf4ac = Hashtag-Spalte hinzufügen
 # Column title for adding last notes column
-Add_Last_Notes_Column_bbad = Letzte Notizen-Spalte hinzufügen
+Add_Last_Notes_Column_bbad64 = Letzte Notizen-Spalte hinzufügen
 # Column title for adding notifications column
-Add_Notifications_Column_79f8 = Benachrichtigungs-Spalte hinzufügen
+Add_Notifications_Column_79f8e2 = Benachrichtigungs-Spalte hinzufügen
 # Button label to add a relay
-Add_relay_269d = Relay hinzufügen
+Add_relay_269d81 = Relay hinzufügen
 # Button label to add a wallet
-Add_Wallet_d1be = Wallet hinzufügen
+Add_Wallet_d1bec0 = Wallet hinzufügen
 # Title for algorithmic feeds column
-Algo_2452 = Algorithmus
+Algo_245216 = Algorithmus
 # Description for algorithmic feeds column
-Algorithmic_feeds_to_aid_in_note_discovery_d344 = Algorithmische Feeds zur Hilfe bei der Entdeckung von Notizen
+Algorithmic_feeds_to_aid_in_note_discovery_d34448 = Algorithmische Feeds zur Hilfe bei der Entdeckung von Notizen
 # Label for zap amount input field
-Amount_70f0 = Menge
+Amount_70f0da = Menge
 # Label for appearance settings section
-Appearance_4c7f = Darstellung
+Appearance_4c7f77 = Darstellung
 # Button to send message to Dave AI assistant
-Ask_b7f4 = Fragen
+Ask_b7f4ce = Fragen
 # Placeholder text for Dave AI input field
-Ask_dave_anything_33d1 = Frage Dave etwas...
+Ask_dave_anything_33d143 = Frage Dave etwas...
 # Profile banner URL field label
-Banner_52ef = Banner
+Banner_52ef05 = Banner
 # Beta version label
-BETA_8e5d = BETA
+BETA_8e5d71 = BETA
 # Broadcast the note to all connected relays
-Broadcast_fe43 = Senden
+Broadcast_fe432d = Senden
 # Broadcast the note only to local network relays
-Broadcast_Local_7e50 = Lokal senden
+Broadcast_Local_7e50ce = Lokal senden
 # Button label to cancel an action
-Cancel_ed3b = Abbrechen
+Cancel_ed3bc2 = Abbrechen
 # Label for cancel clear cache, Storage settings section
-Cancel_fd8b = Abbrechen
+Cancel_fd8ba7 = Abbrechen
 # Label for clear cache button, Storage settings section
-Clear_cache_dccb = Zwischenspeicher leeren
+Clear_cache_dccbdb = Zwischenspeicher leeren
 # Hover text for editable zap amount
-Click_to_edit_0414 = Zum Bearbeiten anklicken
+Click_to_edit_041404 = Zum Bearbeiten anklicken
 # Column title for note composition
-Compose_Note_c094 = Notiz erstellen
+Compose_Note_c0946b = Notiz erstellen
 # Label for configure relays, settings section
-Configure_relays_d156 = Relays konfigurieren
+Configure_relays_d15610 = Relays konfigurieren
 # Label for confirm clear cache, Storage settings section
-Confirm_9d9d = Bestätigen
+Confirm_9d9d74 = Bestätigen
 # Button label to confirm an action
-Confirm_f8a6 = Bestätigen
+Confirm_f8a667 = Bestätigen
 # Status label for connected relay
-Connected_f8cc = Verbunden
+Connected_f8ccd1 = Verbunden
 # Status label for connecting relay
-Connecting_6b7e = Verbinde...
+Connecting_6b7ec3 = Verbinde...
 # Title for contact list column
-Contact_List_f85a = Kontaktliste
+Contact_List_f85a7b = Kontaktliste
 # Column title for contact lists
-Contacts_7533 = Kontakte
+Contacts_7533e6 = Kontakte
 # Column title for last notes per contact
-Contacts__last_notes_3f84 = Kontakte (letzte Notizen)
+Contacts__last_notes_3f84eb = Kontakte (letzte Notizen)
 # Button label to copy logs
-Copy_a688 = Kopieren
+Copy_a688e8 = Kopieren
 # Button to copy media link to clipboard
-Copy_Link_dc7c = Link kopieren
+Copy_Link_dc7c2d = Link kopieren
 # Copy the unique note identifier to clipboard
-Copy_Note_ID_6b45 = Notiz-ID kopieren
+Copy_Note_ID_6b4519 = Notiz-ID kopieren
 # Copy the raw note data in JSON format to clipboard
-Copy_Note_JSON_9e4e = Notiz-JSON kopieren
+Copy_Note_JSON_9e4ebd = Notiz-JSON kopieren
 # Copy the author's public key to clipboard
-Copy_Pubkey_9cc4 = Pubkey kopieren
+Copy_Pubkey_9cc4e2 = Pubkey kopieren
 # Copy the text content of the note to clipboard
-Copy_Text_f81c = Text kopieren
+Copy_Text_f81c61 = Text kopieren
 # Relative time in days
-count_d_b9be = { $count }T
+count_d_b9bee1 = { $count }T
 # Relative time in hours
-count_h_3ecb = { $count }h
+count_h_3ecb5b = { $count }h
 # Relative time in minutes
-count_m_b41e = { $count }min
+count_m_b41e53 = { $count }min
 # Relative time in months
-count_mo_7aba = { $count }M
+count_mo_7aba49 = { $count }M
 # Relative time in seconds
-count_s_aa26 = { $count }s
+count_s_aa26bc = { $count }s
 # Relative time in weeks
-count_w_7468 = { $count }W
+count_w_7468d2 = { $count }W
 # Relative time in years
-count_y_9408 = { $count }J
+count_y_9408bc = { $count }J
 # Button to create a new account
-Create_Account_6994 = Konto erstellen
+Create_Account_6994f5 = Konto erstellen
 # Button label to create a new deck
-Create_Deck_16b7 = Deck erstellen
+Create_Deck_16b7f2 = Deck erstellen
 # Column title for custom timelines
-Custom_a69e = Benutzerdefiniert
+Custom_a69e8d = Benutzerdefiniert
 # Column title for zap amount customization
-Customize_Zap_Amount_cfc4 = Zap-Betrag anpassen
+Customize_Zap_Amount_cfc445 = Zap-Betrag anpassen
 # Column title for support page
-Damus_Support_27c0 = Damus Support
+Damus_Support_27c0c0 = Damus Support
 # Label for Theme Dark, Appearance settings section
-Dark_85fe = Dunkel
+Dark_85feb6 = Dunkel
 # Label for deck name input field
-Deck_name_cd32 = Deck-Name
+Deck_name_cd32b9 = Deck-Name
 # Label for decks section in side panel
-DECKS_1fad = DECKS
+DECKS_1fade2 = DECKS
 # Label for default zap amount input
-Default_amount_per_zap_399d = Standardbetrag pro Zap:
+Default_amount_per_zap_399d11 = Standardbetrag pro Zap:
 # Name of the default deck feed
-Default_Deck_fcca = Standard-Deck
+Default_Deck_fcca5f = Standard-Deck
 # Button label to delete a deck
-Delete_Deck_bb29 = Deck löschen
+Delete_Deck_bb298d = Deck löschen
 # Tooltip for deleting a column
-Delete_this_column_8d5a = Diese Spalte löschen
+Delete_this_column_8d5aab = Diese Spalte löschen
 # Button label to delete a wallet
-Delete_Wallet_d1d4 = Wallet löschen
+Delete_Wallet_d1d48e = Wallet löschen
 # Profile display name field label
-Display_name_f9d9 = Anzeigename
+Display_name_f9d965 = Anzeigename
 # Domain identification message
-domain___will_be_used_for_identification_b67e = "{ $domain }" wird zur Identifikation verwendet
+domain___will_be_used_for_identification_b67ec0 = "{ $domain }" wird zur Identifikation verwendet
 # Column title for editing deck
-Edit_Deck_4018 = Deck bearbeiten
+Edit_Deck_401827 = Deck bearbeiten
 # Button label to edit a deck
-Edit_Deck_fd93 = Deck bearbeiten
+Edit_Deck_fd939c = Deck bearbeiten
 # Button label to edit user profile
-Edit_Profile_49e6 = Profil bearbeiten
+Edit_Profile_49e658 = Profil bearbeiten
 # Column title for profile editing
-Edit_Profile_8ad4 = Profil bearbeiten
+Edit_Profile_8ad45b = Profil bearbeiten
 # Placeholder for hashtag input field
-Enter_the_desired_hashtags_here__for_multiple_space-separated_7a69 = Gewünschte Hashtags hier eingeben (für mehrere, durch Leerzeichen trennen)
+Enter_the_desired_hashtags_here__for_multiple_space-separated_7a695d = Gewünschte Hashtags hier eingeben (für mehrere, durch Leerzeichen trennen)
 # Placeholder for relay input field
-Enter_the_relay_here_1c8b = Relay hier eingeben
+Enter_the_relay_here_1c8bbe = Relay hier eingeben
 # Hint text to prompt entering the user's public key.
-Enter_the_user_s_key__npub__hex__nip05__here_650c = Hier den Benutzerschlüssel (npub, hex, nip05) eingeben...
+Enter_the_user_s_key__npub__hex__nip05__here_650cf4 = Hier den Benutzerschlüssel (npub, hex, nip05) eingeben...
 # Label for key input field. Key can be public key (npub), private key (nsec), or Nostr address (NIP-05).
-Enter_your_key_0fca = Gib deinen Schlüssel ein
+Enter_your_key_0fcaeb = Gib deinen Schlüssel ein
 # Instructions for entering Nostr credentials
-Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9 =
+Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9eb =
     Gib deinen öffentlichen Schlüssel (npub), eine Nostr-Adresse (z. B. {$address}) oder deinen privaten Schlüssel (nsec) ein.
     Für das Veröffentlichen von Beiträgen und andere Aktionen ist dein privater Schlüssel erforderlich.
 # Label for find user button
-Find_User_bd12 = Profil finden
+Find_User_bd120c = Profil finden
 # Label for font size, Appearance settings section
-Font_size_dd73 = Schriftgröße:
+Font_size_dd7325 = Schriftgröße:
 # Title for hashtags column
-Hashtags_f8e0 = Hashtags
+Hashtags_f8e015 = Hashtags
 # Title for Home column
-Home_8c19 = Startseite
+Home_8c1918 = Startseite
 # Label for deck icon selection
-Icon_b0ab = Symbol
+Icon_b0ab7c = Symbol
 # Label for Image cache size, Storage settings section
-Image_cache_size_3004 = Bildcache Größe:
+Image_cache_size_3004a7 = Bildcache Größe:
 # Title for individual user column
-Individual_b776 = Individuell
+Individual_b77693 = Individuell
 # Error message for invalid zap amount
-Invalid_amount_6630 = Ungültiger Betrag
+Invalid_amount_66307c = Ungültiger Betrag
 # Error message for invalid key input
-Invalid_key_4726 = Ungültiger Schlüssel
+Invalid_key_47265a = Ungültiger Schlüssel
 # Error message for invalid Nostr Wallet Connect URI
-Invalid_NWC_URI_031b = Ungültige NWC URI
+Invalid_NWC_URI_031bef = Ungültige NWC URI
 # Zap amount button for 100000 sats. Abbreviated because the button is too small to display the full amount.
-k_100K_686c = 100K
+k_100K_686c79 = 100K
 # Zap amount button for 10000 sats. Abbreviated because the button is too small to display the full amount.
-k_10K_f7e6 = 10K
+k_10K_f7e6df = 10K
 # Zap amount button for 20000 sats. Abbreviated because the button is too small to display the full amount.
-k_20K_4977 = 20K
+k_20K_4977cb = 20K
 # Zap amount button for 50000 sats. Abbreviated because the button is too small to display the full amount.
-k_50K_c2dc = 50K
+k_50K_c2dc4b = 50K
 # Zap amount button for 5000 sats. Abbreviated because the button is too small to display the full amount.
-k_5K_f7e6 = 5K
+k_5K_f7e64e = 5K
 # Description for your notes column
-Keep_track_of_your_notes___replies_a334 = Behalte den Überblick über deine Notizen & Antworten
+Keep_track_of_your_notes___replies_a33477 = Behalte den Überblick über deine Notizen & Antworten
 # Label for language, Appearance settings section
-Language_e264 = Sprache:
+Language_e264a6 = Sprache:
 # Title for last note per user column
-Last_Note_per_User_17ad = Letzte Notiz pro Profil
+Last_Note_per_User_17ad2d = Letzte Notiz pro Profil
 # Label for Theme Light, Appearance settings section
-Light_7475 = Hell
+Light_74751a = Hell
 # Bitcoin Lightning network address field label
-Lightning_network_address__lud16_ea51 = Lightning-Netzwerkadresse (lud16)
+Lightning_network_address__lud16_ea5104 = Lightning-Netzwerkadresse (lud16)
 # Login page title
-Login_9eef = Anmelden
+Login_9eefa9 = Anmelden
 # Login button text
-Login_now___let_s_do_this_5630 = Jetzt anmelden — auf geht's!
+Login_now___let_s_do_this_563039 = Jetzt anmelden — auf geht's!
 # Text shown on blurred media from unfollowed users
-Media_from_someone_you_don_t_follow_5611 = Medien von einem Profil, dem du nicht folgst
+Media_from_someone_you_don_t_follow_56111b = Medien von einem Profil, dem du nicht folgst
 # Tooltip for moving a column
-Moves_this_column_to_another_position_0d4b = Verschiebt diese Spalte an eine andere Position
+Moves_this_column_to_another_position_0d4b4f = Verschiebt diese Spalte an eine andere Position
 # Title for the user's deck
-My_Deck_4ac5 = Mein Deck
+My_Deck_4ac5a8 = Mein Deck
 # Label asking if the user is new to Nostr. Underneath this label is a button to create an account.
-New_to_Nostr_a2fd = Neu bei Nostr?
+New_to_Nostr_a2fd06 = Neu bei Nostr?
+# Negative answer for a yes/no value
+No_6e3f4b = Nein
 # NIP-05 identity field label
-Nostr_address__NIP-05_identity_74a2 = Nostr-Adresse (NIP-05-Identität)
+Nostr_address__NIP-05_identity_74a298 = Nostr-Adresse (NIP-05-Identität)
 # Default username when profile is not available
-nostrich_df29 = Nostrich
+nostrich_df2951 = Nostrich
 # Status label for disconnected relay
-Not_Connected_6292 = Nicht verbunden
+Not_Connected_62928a = Nicht verbunden
 # Link text for note references
-note_cad6 = Notiz
+note_cad63d = Notiz
 # Beta product warning message
-Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a671 = Notedeck ist ein Beta-Produkt. Erwarte Fehler und kontaktiere uns, wenn Probleme oder Fehler auftreten.
+Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a6710e = Notedeck ist ein Beta-Produkt. Erwarte Fehler und kontaktiere uns, wenn Probleme oder Fehler auftreten.
 # Filter label for notes only view
-Notes_03fb = Notizen
+Notes_03fba8 = Notizen
 # Label for notes-only filter
-Notes_60d2 = Notizen
+Notes_60d293 = Notizen
 # Filter label for notes and replies view
-Notes___Replies_1ec2 = Notizen & Antworten
+Notes___Replies_1ec2f3 = Notizen & Antworten
 # Label for notes and replies filter
-Notes___Replies_6e3b = Notizen & Antworten
+Notes___Replies_6e3b3d = Notizen & Antworten
 # Column title for notifications
-Notifications_d673 = Benachrichtigungen
+Notifications_d67387 = Benachrichtigungen
 # Title for notifications column
-Notifications_ef56 = Benachrichtigungen
+Notifications_ef564b = Benachrichtigungen
 # Relative time for very recent events (less than 3 seconds)
-now_2181 = Gerade eben
+now_2181ec = Gerade eben
+# Label for a setting that is turned off
+Off_7b79c1 = Aus
+# Label for a setting that is turned on
+On_ec510c = An
 # Setting to turn on sorting replies so that the newest are shown first
-On_f412 = An
+On_f412d7 = An
 # Button label to open email client
-Open_Email_25e9 = E-Mail öffnen
+Open_Email_25e932 = E-Mail öffnen
 # Instruction to open email client
-Open_your_default_email_client_to_get_help_from_the_Damus_team_68dc = Öffne deinen Standard-E-Mail-Client, um Hilfe vom Damus-Team zu erhalten
+Open_your_default_email_client_to_get_help_from_the_Damus_team_68dce5 = Öffne deinen Standard-E-Mail-Client, um Hilfe vom Damus-Team zu erhalten
 # Label for others settings section
-Others_7267 = Andere
+Others_726757 = Andere
 # Placeholder text for NWC URI input
-Paste_your_NWC_URI_here_b471 = Füge hier deine NWC-URI ein...
+Paste_your_NWC_URI_here_b47163 = Füge hier deine NWC-URI ein...
 # Error message for missing deck name
-Please_create_a_name_for_the_deck_38e7 = Bitte erstelle einen Namen für das Deck.
+Please_create_a_name_for_the_deck_38e717 = Bitte erstelle einen Namen für das Deck.
 # Error message for missing deck name and icon
-Please_create_a_name_for_the_deck_and_select_an_icon_0add = Bitte erstelle einen Namen für das Deck und wähle ein Symbol aus.
+Please_create_a_name_for_the_deck_and_select_an_icon_0add35 = Bitte erstelle einen Namen für das Deck und wähle ein Symbol aus.
 # Error message for missing deck icon
-Please_select_an_icon_655b = Bitte wählen ein Symbol aus.
+Please_select_an_icon_655b5c = Bitte wählen ein Symbol aus.
 # Button label to post a note
-Post_now_8a49 = Jetzt veröffentlichen
+Post_now_8a4984 = Jetzt veröffentlichen
 # Instruction for copying logs
-Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322e = Drücke die Schaltfläche unten, um deine neuesten Protokolle in die Zwischenablage deines Systems zu kopieren. Dann füge sie in deine E-Mail ein.
+Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322eba = Drücke die Schaltfläche unten, um deine neuesten Protokolle in die Zwischenablage deines Systems zu kopieren. Dann füge sie in deine E-Mail ein.
 # Profile picture URL field label
-Profile_picture_81ff = Profilbild
+Profile_picture_81ff30 = Profilbild
 # Column title for quote composition
-Quote_475c = Zitat
+Quote_475cc6 = Zitat
 # Error message when quote note cannot be found
-Quote_of_unknown_note_e4f0 = Zitat von unbekannter Notiz
+Quote_of_unknown_note_e4f086 = Zitat von unbekannter Notiz
 # Label for read-only profile mode
-Read_only_82ff = Nur Lesezugriff
+Read_only_82ffd8 = Nur Lesezugriff
 # Column title for relay management
-Relays_9d89 = Relays
+Relays_9d892a = Relays
 # Label for relay list section
-Relays_ad5e = Relays
+Relays_ad5ef6 = Relays
 # Column title for reply composition
-Reply_3bf1 = Antwort
+Reply_3bf1ce = Antwort
 # Hover text for reply button
-Reply_to_this_note_f5de = Auf diese Notiz antworten
+Reply_to_this_note_f5de5b = Auf diese Notiz antworten
 # Error message when reply note cannot be found
-Reply_to_unknown_note_4401 = Antwort auf unbekannte Notiz
+Reply_to_unknown_note_44019e = Antwort auf unbekannte Notiz
 # Fallback template for replying to user
-replying_to__user_15ab = Antwort an { $user }
+replying_to__user_15ab66 = Antwort an { $user }
 # Template for replying to user in unknown thread
-replying_to__user__in_someone_s_thread_e148 = Antwort an { $user } im Beitrag von jemandem
+replying_to__user__in_someone_s_thread_e14879 = Antwort an { $user } im Beitrag von jemandem
 # Template for replying to note in different user's thread
-replying_to__user__s__note__in__thread_user__s__thread_daa8 = Antwort auf { $user }'s { $note } in { $thread_user }'s { $thread }
+replying_to__user__s__note__in__thread_user__s__thread_daa8ed = Antwort auf { $user }'s { $note } in { $thread_user }'s { $thread }
 # Template for replying to user's note
-replying_to__user__s__note_ccba = Antwort auf { $user }'s { $note }
+replying_to__user__s__note_ccba26 = Antwort auf { $user }'s { $note }
 # Template for replying to root thread
-replying_to__user__s__thread_444d = Antwort auf { $user }'s { $thread }
+replying_to__user__s__thread_444dfb = Antwort auf { $user }'s { $thread }
 # Fallback text when reply note is not found
-replying_to_a_note_e0bc = Antwort auf eine Notiz
+replying_to_a_note_e0bc5c = Antwort auf eine Notiz
 # Hover text for repost button
-Repost_this_note_8e56 = Diese Notiz teilen
+Repost_this_note_8e5605 = Diese Notiz teilen
 # Label for reposted notes
-Reposted_61c8 = Teilen
+Reposted_61c83c = Teilen
 # Label for reset note body font size, Appearance settings section
-Reset_4e60 = Zurücksetzen
+Reset_4e6079 = Zurücksetzen
 # Label for reset zoom level, Appearance settings section
-Reset_62d4 = Zurücksetzen
+Reset_62d48f = Zurücksetzen
 # Heading for support section
-Running_into_a_bug_1796 = Ein Fehler aufgetreten?
+Running_into_a_bug_17962b = Ein Fehler aufgetreten?
 # Label for satoshis (Bitcoin unit) for custom zap amount input field
-SATS_45d7 = SATS
+SATS_45d7cf = SATS
 # Unit label for satoshis (Bitcoin unit) for configuring default zap amount in wallet settings.
-sats_e5ec = Sats
+sats_e5ece2 = Sats
 # Button to save default zap amount
-Save_6f7c = Speichern
+Save_6f7c42 = Speichern
 # Button label to save profile changes
-Save_changes_00db = Änderungen speichern
+Save_changes_00db55 = Änderungen speichern
 # Column title for search page
-Search_c573 = Suche
+Search_c57363 = Suche
 # Placeholder for search notes input field
-Search_notes_42a6 = Notizen suchen...
+Search_notes_42a638 = Notizen suchen...
 # Search in progress message
-Searching_for___query_5d18 = Suche nach '{ $query }'
+Searching_for___query_5d18e6 = Suche nach '{ $query }'
 # Description for Home column
-See_notes_from_your_contacts_ac16 = Notizen von deinen Kontakten ansehen
+See_notes_from_your_contacts_ac1696 = Notizen von deinen Kontakten ansehen
 # Description for universe column
-See_the_whole_nostr_universe_7694 = Sieh dir das ganze Nostr-Universum an
+See_the_whole_nostr_universe_7694cb = Sieh dir das ganze Nostr-Universum an
 # Button label to send a zap
-Send_1ea4 = Senden
+Send_1ea468 = Senden
 # Column title for app settings
-Settings_7a4f = Einstellungen
+Settings_7a4f18 = Einstellungen
 # Description for last note per user column
-Show_the_last_note_for_each_user_from_a_list_50e7 = Zeige die letzte Notiz für jedes Profil aus einer Liste
+Show_the_last_note_for_each_user_from_a_list_50e722 = Zeige die letzte Notiz für jedes Profil aus einer Liste
 # Button label to sign out of account
-Sign_out_337b = Abmelden
+Sign_out_337b00 = Abmelden
 # Title for someone else's notes column
-Someone_else_s_Notes_7e5f = Notizen anderer Profile
+Someone_else_s_Notes_7e5fc1 = Notizen anderer Profile
 # Title for someone else's notifications column
-Someone_else_s_Notifications_82e6 = Mitteilungen anderer Profile
+Someone_else_s_Notifications_82e644 = Mitteilungen anderer Profile
 # Label for Sort replies newest first, others settings section
-Sort_replies_newest_first_b6c3 = Neueste Antworten zuerst sortieren:
+Sort_replies_newest_first_b6c303 = Neueste Antworten zuerst sortieren:
 # Description for contact list column
-Source_the_last_note_for_each_user_in_your_contact_list_e157 = Die letzte Notiz für jedes Profil aus deiner Kontaktliste anzeigen
+Source_the_last_note_for_each_user_in_your_contact_list_e1570f = Die letzte Notiz für jedes Profil aus deiner Kontaktliste anzeigen
 # Description for hashtags column
-Stay_up_to_date_with_a_certain_hashtag_88e3 = Mit einem bestimmten Hashtag auf dem Laufenden bleiben
+Stay_up_to_date_with_a_certain_hashtag_88e3ea = Mit einem bestimmten Hashtag auf dem Laufenden bleiben
 # Description for notifications column
-Stay_up_to_date_with_notifications_and_mentions_6f4e = Bleibe auf dem Laufenden mit Benachrichtigungen und Erwähnungen
+Stay_up_to_date_with_notifications_and_mentions_6f4e31 = Bleibe auf dem Laufenden mit Benachrichtigungen und Erwähnungen
 # Description for someone else's notes column
-Stay_up_to_date_with_someone_else_s_notes___replies_464c = Bleib auf dem Laufenden bei den Notizen & Antworten anderer
+Stay_up_to_date_with_someone_else_s_notes___replies_464c3c = Bleib auf dem Laufenden bei den Notizen & Antworten anderer
 # Description for someone else's notifications column
-Stay_up_to_date_with_someone_else_s_notifications_and_mentions_3473 = Bleib bei den Benachrichtigungen und Erwähnungen anderer auf dem Laufenden
+Stay_up_to_date_with_someone_else_s_notifications_and_mentions_347358 = Bleib bei den Benachrichtigungen und Erwähnungen anderer auf dem Laufenden
 # Description for individual user column
-Stay_up_to_date_with_someone_s_notes___replies_aa78 = Bleib bei den Notizen & Antworten eines anderen auf dem Laufenden
+Stay_up_to_date_with_someone_s_notes___replies_aa7801 = Bleib bei den Notizen & Antworten eines anderen auf dem Laufenden
 # Description for your notifications column
-Stay_up_to_date_with_your_notifications_and_mentions_e73e = Bleib bei deinen Benachrichtigungen und Erwähnungen auf dem Laufenden
+Stay_up_to_date_with_your_notifications_and_mentions_e73ebf = Bleib bei deinen Benachrichtigungen und Erwähnungen auf dem Laufenden
 # Step 1 label in support instructions
-Step_1_8656 = Schritt 1
+Step_1_8656ee = Schritt 1
 # Step 2 label in support instructions
-Step_2_d08d = Schritt 2
+Step_2_d08dd4 = Schritt 2
 # Label for storage settings section
-Storage_ed65 = Speicher
+Storage_ed658c = Speicher
 # Column title for subscribing to external user
-Subscribe_to_someone_else_s_notes_d1e9 = Abonniere die Notizen eines anderen
+Subscribe_to_someone_else_s_notes_d1e927 = Abonniere die Notizen eines anderen
 # Column title for subscribing to individual user
-Subscribe_to_someone_s_notes_b3c8 = Abonniere die Notizen von jemandem
+Subscribe_to_someone_s_notes_b3c806 = Abonniere die Notizen von jemandem
 # Support email address
-Support_email_44d9 = E-Mail Support:
+Support_email_44d972 = E-Mail Support:
 # Hover text for dark mode toggle button
-Switch_to_dark_mode_4dec = Zum Dunkelmodus wechseln
+Switch_to_dark_mode_4dec65 = Zum Dunkelmodus wechseln
 # Hover text for light mode toggle button
-Switch_to_light_mode_72ce = Zum Hellmodus wechseln
+Switch_to_light_mode_72ce5d = Zum Hellmodus wechseln
 # Button text to load blurred media
-Tap_to_Load_4b05 = Zum Laden antippen
+Tap_to_Load_4b05a5 = Zum Laden antippen
 # Message shown when Dave trial period has ended
-The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c7 = Die Testphase des Dave Nostr KI-Assistenten ist beendet :(. Vielen Dank fürs Ausprobieren! Zap-fähiger Dave kommt bald!
+The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c70a = Die Testphase des Dave Nostr KI-Assistenten ist beendet :(. Vielen Dank fürs Ausprobieren! Zap-fähiger Dave kommt bald!
 # Label for theme, Appearance settings section
-Theme_4aac = Design:
+Theme_4aacbd = Design:
 # Column title for note thread view
-Thread_0f20 = Unterhaltung
+Thread_0f2048 = Unterhaltung
 # Link text for thread references
-thread_ad1f = Unterhaltung
+thread_ad1fab = Unterhaltung
 # Title for universe column
-Universe_e01e = Weltraum
+Universe_e01e2c = Weltraum
 # Column title for universe feed
-Universe_ffaa = Weltraum
+Universe_ffaa75 = Weltraum
 # Checkbox label for using wallet only for current account
-Use_this_wallet_for_the_current_account_only_61dc = Diese Wallet nur für das aktuelle Konto verwenden
+Use_this_wallet_for_the_current_account_only_61dc6a = Diese Wallet nur für das aktuelle Konto verwenden
 # Username and domain identification message
-username___at___domain___will_be_used_for_identification_a4fd = "{ $username }" bei "{ $domain }" wird für die Identifikation verwendet werden
+username___at___domain___will_be_used_for_identification_a4fd76 = "{ $username }" bei "{ $domain }" wird für die Identifikation verwendet werden
 # Profile username field label
-Username_daa7 = Benutzername
+Username_daa721 = Benutzername
 # Label for view folder button, Storage settings section
-View_folder_9742 = Ordner anzeigen
+View_folder_9742ca = Ordner anzeigen
 # Column title for wallet management
-Wallet_5e50 = Wallet
+Wallet_5e5099 = Wallet
 # Hint for deck name input field
-We_recommend_short_names_083e = Wir empfehlen kurze Namen
+We_recommend_short_names_083e8e = Wir empfehlen kurze Namen
 # Profile website field label
-Website_7980 = Website
+Website_798018 = Website
 # Placeholder for note input field
-Write_a_banger_note_here_bad2 = Schreib hier eine richtig coole Notiz...
+Write_a_banger_note_here_bad23c = Schreib hier eine richtig coole Notiz...
+# Affirmative answer for a yes/no value
+Yes_2b311b = Ja
 # Placeholder text for key input field
-Your_key_here_81bd = Dein Schlüssel hier...
+Your_key_here_81bdee = Dein Schlüssel hier...
 # Title for your notes column
-Your_Notes_f6db = Deine Notizen
+Your_Notes_f6dbe4 = Deine Notizen
 # Title for your notifications column
-Your_Notifications_080d = Deine Benachrichtigungen
+Your_Notifications_080d5f = Deine Benachrichtigungen
 # Heading for zap (tip) action
-Zap_16b4 = Zap
+Zap_16b444 = Zap
 # Hover text for zap button
-Zap_this_note_42b2 = Zappe diese Notiz
+Zap_this_note_42b210 = Zappe diese Notiz
 # Label for zoom level, Appearance settings section
-Zoom_Level_29a8 = Zoomstufe:
+Zoom_Level_29a8c0 = Zoomstufe:
 
 # Pluralized strings
 
 # Search results count
-Got__count__results_for___query_85fb =
+Got__count__results_for___query_85fbf5 =
     { $count ->
         [one] { $count } Ergebnis für '{ $query } gefunden'
        *[other] { $count } Ergebnisse für '{ $query } gefunden'
@@ -6,404 +6,404 @@
 # Regular strings
 
 # Profile about/bio field label
-About_00c0 = Información
+About_00c04c = Información
 # Column title for account management
-Accounts_f018 = Cuentas
+Accounts_f01844 = Cuentas
 # Button label to add a relay
-Add_269d = Añadir
+Add_269d81 = Añadir
 # Label for add column button
-Add_47df = Añadir
+Add_47dfb8 = Añadir
 # Button label to add a different wallet
-Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d = Añadir un monedero diferente que solo se utilizará para esta cuenta
+Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d48 = Añadir un monedero diferente que solo se utilizará para esta cuenta
 # Error message for missing wallet
-Add_a_wallet_to_continue_d170 = Añadir un monedero para continuar
+Add_a_wallet_to_continue_d170b3 = Añadir un monedero para continuar
 # Button label to add a new account
-Add_account_1cfc = Añadir cuenta
+Add_account_1cfc87 = Añadir cuenta
 # Column title for adding new account
-Add_Account_d06c = Añadir cuenta
+Add_Account_d06c6a = Añadir cuenta
 # Column title for adding algorithm column
-Add_Algo_Column_0d75 = Añadir columna algorítmica
+Add_Algo_Column_0d751c = Añadir columna algorítmica
 # Column title for adding new column
-Add_Column_c764 = Añadir columna
+Add_Column_c764c5 = Añadir columna
 # Column title for adding new deck
-Add_Deck_fabf = Añadir deck
+Add_Deck_fabf9d = Añadir deck
 # Column title for adding external notifications column
-Add_External_Notifications_Column_41ae = Añadir columna de notificaciones externas
+Add_External_Notifications_Column_41aee7 = Añadir columna de notificaciones externas
 # Column title for adding hashtag column
-Add_Hashtag_Column_ebf4 = Añadir columna de hashtags
+Add_Hashtag_Column_ebf4ac = Añadir columna de hashtags
 # Column title for adding last notes column
-Add_Last_Notes_Column_bbad = Añadir columna de últimas notas
+Add_Last_Notes_Column_bbad64 = Añadir columna de últimas notas
 # Column title for adding notifications column
-Add_Notifications_Column_79f8 = Añadir columna de notificaciones
+Add_Notifications_Column_79f8e2 = Añadir columna de notificaciones
 # Button label to add a relay
-Add_relay_269d = Añadir relé
+Add_relay_269d81 = Añadir relé
 # Button label to add a wallet
-Add_Wallet_d1be = Añadir monedero
+Add_Wallet_d1bec0 = Añadir monedero
 # Title for algorithmic feeds column
-Algo_2452 = Algo
+Algo_245216 = Algo
 # Description for algorithmic feeds column
-Algorithmic_feeds_to_aid_in_note_discovery_d344 = Feeds algorítmicos para ayudar en el descubrimiento de notas
+Algorithmic_feeds_to_aid_in_note_discovery_d34448 = Feeds algorítmicos para ayudar en el descubrimiento de notas
 # Label for zap amount input field
-Amount_70f0 = Cantidad
+Amount_70f0da = Cantidad
 # Label for appearance settings section
-Appearance_4c7f = Aspecto
+Appearance_4c7f77 = Aspecto
 # Button to send message to Dave AI assistant
-Ask_b7f4 = Preguntar
+Ask_b7f4ce = Preguntar
 # Placeholder text for Dave AI input field
-Ask_dave_anything_33d1 = Pregúntale cualquier cosa a Dave...
+Ask_dave_anything_33d143 = Pregúntale cualquier cosa a Dave...
 # Profile banner URL field label
-Banner_52ef = Banner
+Banner_52ef05 = Banner
 # Beta version label
-BETA_8e5d = BETA
+BETA_8e5d71 = BETA
 # Broadcast the note to all connected relays
-Broadcast_fe43 = Transmitir
+Broadcast_fe432d = Transmitir
 # Broadcast the note only to local network relays
-Broadcast_Local_7e50 = Transmitir localmente
+Broadcast_Local_7e50ce = Transmitir localmente
 # Button label to cancel an action
-Cancel_ed3b = Cancelar
+Cancel_ed3bc2 = Cancelar
 # Label for cancel clear cache, Storage settings section
-Cancel_fd8b = Cancelar
+Cancel_fd8ba7 = Cancelar
 # Label for clear cache button, Storage settings section
-Clear_cache_dccb = Limpiar caché
+Clear_cache_dccbdb = Limpiar caché
 # Hover text for editable zap amount
-Click_to_edit_0414 = Haz clic para editar
+Click_to_edit_041404 = Haz clic para editar
 # Column title for note composition
-Compose_Note_c094 = Redactar nota
+Compose_Note_c0946b = Redactar nota
 # Label for configure relays, settings section
-Configure_relays_d156 = Configurar relés
+Configure_relays_d15610 = Configurar relés
 # Label for confirm clear cache, Storage settings section
-Confirm_9d9d = Confirmar
+Confirm_9d9d74 = Confirmar
 # Button label to confirm an action
-Confirm_f8a6 = Confirmar
+Confirm_f8a667 = Confirmar
 # Status label for connected relay
-Connected_f8cc = Conectado
+Connected_f8ccd1 = Conectado
 # Status label for connecting relay
-Connecting_6b7e = Conectando...
+Connecting_6b7ec3 = Conectando...
 # Title for contact list column
-Contact_List_f85a = Lista de contactos
+Contact_List_f85a7b = Lista de contactos
 # Column title for contact lists
-Contacts_7533 = Contactos
+Contacts_7533e6 = Contactos
 # Column title for last notes per contact
-Contacts__last_notes_3f84 = Contactos (últimas notas)
+Contacts__last_notes_3f84eb = Contactos (últimas notas)
 # Button label to copy logs
-Copy_a688 = Copiar
+Copy_a688e8 = Copiar
 # Button to copy media link to clipboard
-Copy_Link_dc7c = Copiar enlace
+Copy_Link_dc7c2d = Copiar enlace
 # Copy the unique note identifier to clipboard
-Copy_Note_ID_6b45 = Copiar ID de nota
+Copy_Note_ID_6b4519 = Copiar ID de nota
 # Copy the raw note data in JSON format to clipboard
-Copy_Note_JSON_9e4e = Copiar JSON de nota
+Copy_Note_JSON_9e4ebd = Copiar JSON de nota
 # Copy the author's public key to clipboard
-Copy_Pubkey_9cc4 = Copiar pubkey
+Copy_Pubkey_9cc4e2 = Copiar pubkey
 # Copy the text content of the note to clipboard
-Copy_Text_f81c = Copiar texto
+Copy_Text_f81c61 = Copiar texto
 # Relative time in days
-count_d_b9be = { $count }d
+count_d_b9bee1 = { $count }d
 # Relative time in hours
-count_h_3ecb = { $count }h
+count_h_3ecb5b = { $count }h
 # Relative time in minutes
-count_m_b41e = { $count }m
+count_m_b41e53 = { $count }m
 # Relative time in months
-count_mo_7aba = { $count }mes
+count_mo_7aba49 = { $count }mes
 # Relative time in seconds
-count_s_aa26 = { $count }s
+count_s_aa26bc = { $count }s
 # Relative time in weeks
-count_w_7468 = { $count }sem
+count_w_7468d2 = { $count }sem
 # Relative time in years
-count_y_9408 = { $count }a
+count_y_9408bc = { $count }a
 # Button to create a new account
-Create_Account_6994 = Crear cuenta
+Create_Account_6994f5 = Crear cuenta
 # Button label to create a new deck
-Create_Deck_16b7 = Crear deck
+Create_Deck_16b7f2 = Crear deck
 # Column title for custom timelines
-Custom_a69e = Personalizado
+Custom_a69e8d = Personalizado
 # Column title for zap amount customization
-Customize_Zap_Amount_cfc4 = Personalizar cantidad de zap
+Customize_Zap_Amount_cfc445 = Personalizar cantidad de zap
 # Column title for support page
-Damus_Support_27c0 = Ayuda de Damus
+Damus_Support_27c0c0 = Ayuda de Damus
 # Label for Theme Dark, Appearance settings section
-Dark_85fe = Oscuro
+Dark_85feb6 = Oscuro
 # Label for deck name input field
-Deck_name_cd32 = Nombre del deck
+Deck_name_cd32b9 = Nombre del deck
 # Label for decks section in side panel
-DECKS_1fad = DECKS
+DECKS_1fade2 = DECKS
 # Label for default zap amount input
-Default_amount_per_zap_399d = Cantidad predeterminada por zap:
+Default_amount_per_zap_399d11 = Cantidad predeterminada por zap:
 # Name of the default deck feed
-Default_Deck_fcca = Deck predeterminado
+Default_Deck_fcca5f = Deck predeterminado
 # Button label to delete a deck
-Delete_Deck_bb29 = Eliminar deck
+Delete_Deck_bb298d = Eliminar deck
 # Tooltip for deleting a column
-Delete_this_column_8d5a = Eliminar esta columna
+Delete_this_column_8d5aab = Eliminar esta columna
 # Button label to delete a wallet
-Delete_Wallet_d1d4 = Eliminar monedero
+Delete_Wallet_d1d48e = Eliminar monedero
 # Profile display name field label
-Display_name_f9d9 = Nombre para mostrar
+Display_name_f9d965 = Nombre para mostrar
 # Domain identification message
-domain___will_be_used_for_identification_b67e = "{ $domain }" se utilizará para la identificación
+domain___will_be_used_for_identification_b67ec0 = "{ $domain }" se utilizará para la identificación
 # Column title for editing deck
-Edit_Deck_4018 = Editar deck
+Edit_Deck_401827 = Editar deck
 # Button label to edit a deck
-Edit_Deck_fd93 = Editar deck
+Edit_Deck_fd939c = Editar deck
 # Button label to edit user profile
-Edit_Profile_49e6 = Editar perfil
+Edit_Profile_49e658 = Editar perfil
 # Column title for profile editing
-Edit_Profile_8ad4 = Editar perfil
+Edit_Profile_8ad45b = Editar perfil
 # Placeholder for hashtag input field
-Enter_the_desired_hashtags_here__for_multiple_space-separated_7a69 = Ingresa aquí los hashtags deseados (si son varios, sepáralos con un espacio)
+Enter_the_desired_hashtags_here__for_multiple_space-separated_7a695d = Ingresa aquí los hashtags deseados (si son varios, sepáralos con un espacio)
 # Placeholder for relay input field
-Enter_the_relay_here_1c8b = Ingresa el relé aquí
+Enter_the_relay_here_1c8bbe = Ingresa el relé aquí
 # Hint text to prompt entering the user's public key.
-Enter_the_user_s_key__npub__hex__nip05__here_650c = Ingresa la clave del usuario (npub, hex, nip05) aquí...
+Enter_the_user_s_key__npub__hex__nip05__here_650cf4 = Ingresa la clave del usuario (npub, hex, nip05) aquí...
 # Label for key input field. Key can be public key (npub), private key (nsec), or Nostr address (NIP-05).
-Enter_your_key_0fca = Ingresa tu clave
+Enter_your_key_0fcaeb = Ingresa tu clave
 # Instructions for entering Nostr credentials
-Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9 = Ingresa tu clave pública (npub), dirección de Nostr (por ejemplo, { $address }) o clave privada (nsec). Debes ingresar tu clave privada para poder publicar, responder, etc.
+Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9eb = Ingresa tu clave pública (npub), dirección de Nostr (por ejemplo, { $address }) o clave privada (nsec). Debes ingresar tu clave privada para poder publicar, responder, etc.
 # Label for find user button
-Find_User_bd12 = Buscar usuario
+Find_User_bd120c = Buscar usuario
 # Label for font size, Appearance settings section
-Font_size_dd73 = Font size:
+Font_size_dd7325 = Font size:
 # Title for hashtags column
-Hashtags_f8e0 = Hashtags
+Hashtags_f8e015 = Hashtags
 # Title for Home column
-Home_8c19 = Inicio
+Home_8c1918 = Inicio
 # Label for deck icon selection
-Icon_b0ab = Icono
+Icon_b0ab7c = Icono
 # Label for Image cache size, Storage settings section
-Image_cache_size_3004 = Tamaño de caché de imágenes:
+Image_cache_size_3004a7 = Tamaño de caché de imágenes:
 # Title for individual user column
-Individual_b776 = Individual
+Individual_b77693 = Individual
 # Error message for invalid zap amount
-Invalid_amount_6630 = Cantidad no válida
+Invalid_amount_66307c = Cantidad no válida
 # Error message for invalid key input
-Invalid_key_4726 = Clave no válida.
+Invalid_key_47265a = Clave no válida.
 # Error message for invalid Nostr Wallet Connect URI
-Invalid_NWC_URI_031b = NWC URI no válido
+Invalid_NWC_URI_031bef = NWC URI no válido
 # Zap amount button for 100000 sats. Abbreviated because the button is too small to display the full amount.
-k_100K_686c = 100.000
+k_100K_686c79 = 100.000
 # Zap amount button for 10000 sats. Abbreviated because the button is too small to display the full amount.
-k_10K_f7e6 = 10.000
+k_10K_f7e6df = 10.000
 # Zap amount button for 20000 sats. Abbreviated because the button is too small to display the full amount.
-k_20K_4977 = 20.000
+k_20K_4977cb = 20.000
 # Zap amount button for 50000 sats. Abbreviated because the button is too small to display the full amount.
-k_50K_c2dc = 50.000
+k_50K_c2dc4b = 50.000
 # Zap amount button for 5000 sats. Abbreviated because the button is too small to display the full amount.
-k_5K_f7e6 = 5.000
+k_5K_f7e64e = 5.000
 # Description for your notes column
-Keep_track_of_your_notes___replies_a334 = Haz seguimiento de tus notas y respuestas
+Keep_track_of_your_notes___replies_a33477 = Haz seguimiento de tus notas y respuestas
 # Label for language, Appearance settings section
-Language_e264 = Idioma:
+Language_e264a6 = Idioma:
 # Title for last note per user column
-Last_Note_per_User_17ad = Última nota por usuario
+Last_Note_per_User_17ad2d = Última nota por usuario
 # Label for Theme Light, Appearance settings section
-Light_7475 = Claro
+Light_74751a = Claro
 # Bitcoin Lightning network address field label
-Lightning_network_address__lud16_ea51 = Dirección de la red Lightning (lud16)
+Lightning_network_address__lud16_ea5104 = Dirección de la red Lightning (lud16)
 # Login page title
-Login_9eef = Inicio de sesión
+Login_9eefa9 = Inicio de sesión
 # Login button text
-Login_now___let_s_do_this_5630 = Inicia sesión ahora, ¡manos a la obra!
+Login_now___let_s_do_this_563039 = Inicia sesión ahora, ¡manos a la obra!
 # Text shown on blurred media from unfollowed users
-Media_from_someone_you_don_t_follow_5611 = Contenido multimedia de alguien que no sigues
+Media_from_someone_you_don_t_follow_56111b = Contenido multimedia de alguien que no sigues
 # Tooltip for moving a column
-Moves_this_column_to_another_position_0d4b = Mueve esta columna a otra posición
+Moves_this_column_to_another_position_0d4b4f = Mueve esta columna a otra posición
 # Title for the user's deck
-My_Deck_4ac5 = Mi deck
+My_Deck_4ac5a8 = Mi deck
 # Label asking if the user is new to Nostr. Underneath this label is a button to create an account.
-New_to_Nostr_a2fd = ¿Primera vez en Nostr?
+New_to_Nostr_a2fd06 = ¿Primera vez en Nostr?
 # NIP-05 identity field label
-Nostr_address__NIP-05_identity_74a2 = Dirección de Nostr (identidad NIP-05)
+Nostr_address__NIP-05_identity_74a298 = Dirección de Nostr (identidad NIP-05)
 # Default username when profile is not available
-nostrich_df29 = nostrich
+nostrich_df2951 = nostrich
 # Status label for disconnected relay
-Not_Connected_6292 = No conectado
+Not_Connected_62928a = No conectado
 # Link text for note references
-note_cad6 = nota
+note_cad63d = nota
 # Beta product warning message
-Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a671 = Notedeck es un producto en fase beta. Es posible que haya errores, así que ponte en contacto con nosotros si tienes algún problema.
+Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a6710e = Notedeck es un producto en fase beta. Es posible que haya errores, así que ponte en contacto con nosotros si tienes algún problema.
 # Filter label for notes only view
-Notes_03fb = Notas
+Notes_03fba8 = Notas
 # Label for notes-only filter
-Notes_60d2 = Notas
+Notes_60d293 = Notas
 # Filter label for notes and replies view
-Notes___Replies_1ec2 = Notas y respuestas
+Notes___Replies_1ec2f3 = Notas y respuestas
 # Label for notes and replies filter
-Notes___Replies_6e3b = Notas y respuestas
+Notes___Replies_6e3b3d = Notas y respuestas
 # Column title for notifications
-Notifications_d673 = Notificaciones
+Notifications_d67387 = Notificaciones
 # Title for notifications column
-Notifications_ef56 = Notificaciones
+Notifications_ef564b = Notificaciones
 # Relative time for very recent events (less than 3 seconds)
-now_2181 = ahora
+now_2181ec = ahora
 # Setting to turn on sorting replies so that the newest are shown first
-On_f412 = On
+On_f412d7 = On
 # Button label to open email client
-Open_Email_25e9 = Abrir correo electrónico
+Open_Email_25e932 = Abrir correo electrónico
 # Instruction to open email client
-Open_your_default_email_client_to_get_help_from_the_Damus_team_68dc = Abre tu cliente de correo predeterminado para recibir ayuda del equipo de Damus
+Open_your_default_email_client_to_get_help_from_the_Damus_team_68dce5 = Abre tu cliente de correo predeterminado para recibir ayuda del equipo de Damus
 # Label for others settings section
-Others_7267 = Otros
+Others_726757 = Otros
 # Placeholder text for NWC URI input
-Paste_your_NWC_URI_here_b471 = Pega tu NWC URI aquí...
+Paste_your_NWC_URI_here_b47163 = Pega tu NWC URI aquí...
 # Error message for missing deck name
-Please_create_a_name_for_the_deck_38e7 = Crea un nombre para el Deck.
+Please_create_a_name_for_the_deck_38e717 = Crea un nombre para el Deck.
 # Error message for missing deck name and icon
-Please_create_a_name_for_the_deck_and_select_an_icon_0add = Crea un nombre para el deck y selecciona un icono.
+Please_create_a_name_for_the_deck_and_select_an_icon_0add35 = Crea un nombre para el deck y selecciona un icono.
 # Error message for missing deck icon
-Please_select_an_icon_655b = Selecciona un icono.
+Please_select_an_icon_655b5c = Selecciona un icono.
 # Button label to post a note
-Post_now_8a49 = Publicar ahora
+Post_now_8a4984 = Publicar ahora
 # Instruction for copying logs
-Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322e = Presiona el siguiente botón para copiar los registros más recientes al portapapeles del sistema. A continuación, pégalos en tu correo electrónico.
+Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322eba = Presiona el siguiente botón para copiar los registros más recientes al portapapeles del sistema. A continuación, pégalos en tu correo electrónico.
 # Profile picture URL field label
-Profile_picture_81ff = Imagen de perfil
+Profile_picture_81ff30 = Imagen de perfil
 # Column title for quote composition
-Quote_475c = Citar
+Quote_475cc6 = Citar
 # Error message when quote note cannot be found
-Quote_of_unknown_note_e4f0 = Cita de nota desconocida
+Quote_of_unknown_note_e4f086 = Cita de nota desconocida
 # Label for read-only profile mode
-Read_only_82ff = Solo lectura
+Read_only_82ffd8 = Solo lectura
 # Column title for relay management
-Relays_9d89 = Relés
+Relays_9d892a = Relés
 # Label for relay list section
-Relays_ad5e = Relés
+Relays_ad5ef6 = Relés
 # Column title for reply composition
-Reply_3bf1 = Respuesta
+Reply_3bf1ce = Respuesta
 # Hover text for reply button
-Reply_to_this_note_f5de = Responder a esta nota
+Reply_to_this_note_f5de5b = Responder a esta nota
 # Error message when reply note cannot be found
-Reply_to_unknown_note_4401 = Responder a nota desconocida
+Reply_to_unknown_note_44019e = Responder a nota desconocida
 # Fallback template for replying to user
-replying_to__user_15ab = respondiendo a { $user }
+replying_to__user_15ab66 = respondiendo a { $user }
 # Template for replying to user in unknown thread
-replying_to__user__in_someone_s_thread_e148 = respondiendo a { $user } en la conversación de alguien
+replying_to__user__in_someone_s_thread_e14879 = respondiendo a { $user } en la conversación de alguien
 # Template for replying to note in different user's thread
-replying_to__user__s__note__in__thread_user__s__thread_daa8 = respondiendo a { $note } de { $user } en { $thread } de { $thread_user }
+replying_to__user__s__note__in__thread_user__s__thread_daa8ed = respondiendo a { $note } de { $user } en { $thread } de { $thread_user }
 # Template for replying to user's note
-replying_to__user__s__note_ccba = respondiendo a { $note } de { $user }
+replying_to__user__s__note_ccba26 = respondiendo a { $note } de { $user }
 # Template for replying to root thread
-replying_to__user__s__thread_444d = respondiendo a { $thread } de { $user }
+replying_to__user__s__thread_444dfb = respondiendo a { $thread } de { $user }
 # Fallback text when reply note is not found
-replying_to_a_note_e0bc = respondiendo a una nota
+replying_to_a_note_e0bc5c = respondiendo a una nota
 # Hover text for repost button
-Repost_this_note_8e56 = Volver a publicar esta nota
+Repost_this_note_8e5605 = Volver a publicar esta nota
 # Label for reposted notes
-Reposted_61c8 = Publicadas de nuevo
+Reposted_61c83c = Publicadas de nuevo
 # Label for reset note body font size, Appearance settings section
-Reset_4e60 = Reset
+Reset_4e6079 = Reset
 # Label for reset zoom level, Appearance settings section
-Reset_62d4 = Restablecer
+Reset_62d48f = Restablecer
 # Heading for support section
-Running_into_a_bug_1796 = ¿Has encontrado un error?
+Running_into_a_bug_17962b = ¿Has encontrado un error?
 # Label for satoshis (Bitcoin unit) for custom zap amount input field
-SATS_45d7 = SATS
+SATS_45d7cf = SATS
 # Unit label for satoshis (Bitcoin unit) for configuring default zap amount in wallet settings.
-sats_e5ec = sats
+sats_e5ece2 = sats
 # Button to save default zap amount
-Save_6f7c = Guardar
+Save_6f7c42 = Guardar
 # Button label to save profile changes
-Save_changes_00db = Guardar cambios
+Save_changes_00db55 = Guardar cambios
 # Column title for search page
-Search_c573 = Búsqueda
+Search_c57363 = Búsqueda
 # Placeholder for search notes input field
-Search_notes_42a6 = Buscar notas...
+Search_notes_42a638 = Buscar notas...
 # Search in progress message
-Searching_for___query_5d18 = Buscando '{ $query }'
+Searching_for___query_5d18e6 = Buscando '{ $query }'
 # Description for Home column
-See_notes_from_your_contacts_ac16 = Ver notas de tus contactos
+See_notes_from_your_contacts_ac1696 = Ver notas de tus contactos
 # Description for universe column
-See_the_whole_nostr_universe_7694 = Ver todo el universo de nostr
+See_the_whole_nostr_universe_7694cb = Ver todo el universo de nostr
 # Button label to send a zap
-Send_1ea4 = Enviar
+Send_1ea468 = Enviar
 # Column title for app settings
-Settings_7a4f = Configuración
+Settings_7a4f18 = Configuración
 # Description for last note per user column
-Show_the_last_note_for_each_user_from_a_list_50e7 = Mostrar la última nota para cada usuario de una lista
+Show_the_last_note_for_each_user_from_a_list_50e722 = Mostrar la última nota para cada usuario de una lista
 # Button label to sign out of account
-Sign_out_337b = Cerrar sesión
+Sign_out_337b00 = Cerrar sesión
 # Title for someone else's notes column
-Someone_else_s_Notes_7e5f = Notas de otra persona
+Someone_else_s_Notes_7e5fc1 = Notas de otra persona
 # Title for someone else's notifications column
-Someone_else_s_Notifications_82e6 = Notificaciones de otra persona
+Someone_else_s_Notifications_82e644 = Notificaciones de otra persona
 # Label for Sort replies newest first, others settings section
-Sort_replies_newest_first_b6c3 = Sort replies newest first:
+Sort_replies_newest_first_b6c303 = Sort replies newest first:
 # Description for contact list column
-Source_the_last_note_for_each_user_in_your_contact_list_e157 = Busca la última nota de cada usuario en tu lista de contactos
+Source_the_last_note_for_each_user_in_your_contact_list_e1570f = Busca la última nota de cada usuario en tu lista de contactos
 # Description for hashtags column
-Stay_up_to_date_with_a_certain_hashtag_88e3 = Mantente al día con un hashtag específico
+Stay_up_to_date_with_a_certain_hashtag_88e3ea = Mantente al día con un hashtag específico
 # Description for notifications column
-Stay_up_to_date_with_notifications_and_mentions_6f4e = Mantente al día con notificaciones y menciones
+Stay_up_to_date_with_notifications_and_mentions_6f4e31 = Mantente al día con notificaciones y menciones
 # Description for someone else's notes column
-Stay_up_to_date_with_someone_else_s_notes___replies_464c = Mantente al día con las notas y respuestas de otra persona
+Stay_up_to_date_with_someone_else_s_notes___replies_464c3c = Mantente al día con las notas y respuestas de otra persona
 # Description for someone else's notifications column
-Stay_up_to_date_with_someone_else_s_notifications_and_mentions_3473 = Mantente al día con las notificaciones y menciones de otra persona
+Stay_up_to_date_with_someone_else_s_notifications_and_mentions_347358 = Mantente al día con las notificaciones y menciones de otra persona
 # Description for individual user column
-Stay_up_to_date_with_someone_s_notes___replies_aa78 = Mantente al día con las notas y respuestas de alguien
+Stay_up_to_date_with_someone_s_notes___replies_aa7801 = Mantente al día con las notas y respuestas de alguien
 # Description for your notifications column
-Stay_up_to_date_with_your_notifications_and_mentions_e73e = Mantente al día con tus notificaciones y menciones
+Stay_up_to_date_with_your_notifications_and_mentions_e73ebf = Mantente al día con tus notificaciones y menciones
 # Step 1 label in support instructions
-Step_1_8656 = Paso 1
+Step_1_8656ee = Paso 1
 # Step 2 label in support instructions
-Step_2_d08d = Paso 2
+Step_2_d08dd4 = Paso 2
 # Label for storage settings section
-Storage_ed65 = Almacenamiento
+Storage_ed658c = Almacenamiento
 # Column title for subscribing to external user
-Subscribe_to_someone_else_s_notes_d1e9 = Suscribirse a las notas de otra persona
+Subscribe_to_someone_else_s_notes_d1e927 = Suscribirse a las notas de otra persona
 # Column title for subscribing to individual user
-Subscribe_to_someone_s_notes_b3c8 = Suscribirse a las notas de alguien
+Subscribe_to_someone_s_notes_b3c806 = Suscribirse a las notas de alguien
 # Support email address
-Support_email_44d9 = Support email:
+Support_email_44d972 = Support email:
 # Hover text for dark mode toggle button
-Switch_to_dark_mode_4dec = Cambiar a modo oscuro
+Switch_to_dark_mode_4dec65 = Cambiar a modo oscuro
 # Hover text for light mode toggle button
-Switch_to_light_mode_72ce = Cambiar a modo claro
+Switch_to_light_mode_72ce5d = Cambiar a modo claro
 # Button text to load blurred media
-Tap_to_Load_4b05 = Toca para cargar
+Tap_to_Load_4b05a5 = Toca para cargar
 # Message shown when Dave trial period has ended
-The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c7 = La prueba del asistente de IA Dave de Nostr ha finalizado :(. ¡Gracias por probarlo! ¡Dave con zaps estará disponible muy pronto!
+The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c70a = La prueba del asistente de IA Dave de Nostr ha finalizado :(. ¡Gracias por probarlo! ¡Dave con zaps estará disponible muy pronto!
 # Label for theme, Appearance settings section
-Theme_4aac = Tema:
+Theme_4aacbd = Tema:
 # Column title for note thread view
-Thread_0f20 = Conversación
+Thread_0f2048 = Conversación
 # Link text for thread references
-thread_ad1f = conversación
+thread_ad1fab = conversación
 # Title for universe column
-Universe_e01e = Universo
+Universe_e01e2c = Universo
 # Column title for universe feed
-Universe_ffaa = Universo
+Universe_ffaa75 = Universo
 # Checkbox label for using wallet only for current account
-Use_this_wallet_for_the_current_account_only_61dc = Usar este monedero solo para la cuenta actual
+Use_this_wallet_for_the_current_account_only_61dc6a = Usar este monedero solo para la cuenta actual
 # Username and domain identification message
-username___at___domain___will_be_used_for_identification_a4fd = Se utilizará "{ $username }" en "{ $domain }" para la identificación
+username___at___domain___will_be_used_for_identification_a4fd76 = Se utilizará "{ $username }" en "{ $domain }" para la identificación
 # Profile username field label
-Username_daa7 = Nombre de usuario
+Username_daa721 = Nombre de usuario
 # Label for view folder button, Storage settings section
-View_folder_9742 = Ver carpeta
+View_folder_9742ca = Ver carpeta
 # Column title for wallet management
-Wallet_5e50 = Monedero
+Wallet_5e5099 = Monedero
 # Hint for deck name input field
-We_recommend_short_names_083e = Recomendamos nombres cortos
+We_recommend_short_names_083e8e = Recomendamos nombres cortos
 # Profile website field label
-Website_7980 = Sitio web
+Website_798018 = Sitio web
 # Placeholder for note input field
-Write_a_banger_note_here_bad2 = Escribe aquí una nota impactante...
+Write_a_banger_note_here_bad23c = Escribe aquí una nota impactante...
 # Placeholder text for key input field
-Your_key_here_81bd = Tu clave aquí...
+Your_key_here_81bdee = Tu clave aquí...
 # Title for your notes column
-Your_Notes_f6db = Tus notas
+Your_Notes_f6dbe4 = Tus notas
 # Title for your notifications column
-Your_Notifications_080d = Tus notificaciones
+Your_Notifications_080d5f = Tus notificaciones
 # Heading for zap (tip) action
-Zap_16b4 = Zap
+Zap_16b444 = Zap
 # Hover text for zap button
-Zap_this_note_42b2 = Enviar un zap a esta nota
+Zap_this_note_42b210 = Enviar un zap a esta nota
 # Label for zoom level, Appearance settings section
-Zoom_Level_29a8 = Nivel de zoom:
+Zoom_Level_29a8c0 = Nivel de zoom:
 
 # Pluralized strings
 
 # Search results count
-Got__count__results_for___query_85fb =
+Got__count__results_for___query_85fbf5 =
     { $count ->
         [uno] Obtuvo { $count } resultado para '{ $query }'
        *[otro] Obtuvo { $count } resultados para '{ $query }'
@@ -6,404 +6,404 @@
 # Regular strings
 
 # Profile about/bio field label
-About_00c0 = A propos
+About_00c04c = A propos
 # Column title for account management
-Accounts_f018 = Comptes
+Accounts_f01844 = Comptes
 # Button label to add a relay
-Add_269d = Ajouter
+Add_269d81 = Ajouter
 # Label for add column button
-Add_47df = Ajouter
+Add_47dfb8 = Ajouter
 # Button label to add a different wallet
-Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d = Ajouter un portefeuille différent qui ne sera utilisé que pour ce compte
+Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d48 = Ajouter un portefeuille différent qui ne sera utilisé que pour ce compte
 # Error message for missing wallet
-Add_a_wallet_to_continue_d170 = Ajouter un portefeuille pour continuer
+Add_a_wallet_to_continue_d170b3 = Ajouter un portefeuille pour continuer
 # Button label to add a new account
-Add_account_1cfc = Ajouter un compte
+Add_account_1cfc87 = Ajouter un compte
 # Column title for adding new account
-Add_Account_d06c = Ajouter un compte
+Add_Account_d06c6a = Ajouter un compte
 # Column title for adding algorithm column
-Add_Algo_Column_0d75 = Ajouter une colonne Algo
+Add_Algo_Column_0d751c = Ajouter une colonne Algo
 # Column title for adding new column
-Add_Column_c764 = Ajouter une colonne
+Add_Column_c764c5 = Ajouter une colonne
 # Column title for adding new deck
-Add_Deck_fabf = Ajouter un deck
+Add_Deck_fabf9d = Ajouter un deck
 # Column title for adding external notifications column
-Add_External_Notifications_Column_41ae = Ajouter une colonne pour les notifications externes
+Add_External_Notifications_Column_41aee7 = Ajouter une colonne pour les notifications externes
 # Column title for adding hashtag column
-Add_Hashtag_Column_ebf4 = Ajouter une colonne Hashtag
+Add_Hashtag_Column_ebf4ac = Ajouter une colonne Hashtag
 # Column title for adding last notes column
-Add_Last_Notes_Column_bbad = Ajouter une colonne pour les dernières notes
+Add_Last_Notes_Column_bbad64 = Ajouter une colonne pour les dernières notes
 # Column title for adding notifications column
-Add_Notifications_Column_79f8 = Ajouter une colonne pour les notifications
+Add_Notifications_Column_79f8e2 = Ajouter une colonne pour les notifications
 # Button label to add a relay
-Add_relay_269d = Ajouter un relai
+Add_relay_269d81 = Ajouter un relai
 # Button label to add a wallet
-Add_Wallet_d1be = Ajouter un portefeuille
+Add_Wallet_d1bec0 = Ajouter un portefeuille
 # Title for algorithmic feeds column
-Algo_2452 = Algo
+Algo_245216 = Algo
 # Description for algorithmic feeds column
-Algorithmic_feeds_to_aid_in_note_discovery_d344 = Des fils algorithmiques pour faciliter la découverte de notes
+Algorithmic_feeds_to_aid_in_note_discovery_d34448 = Des fils algorithmiques pour faciliter la découverte de notes
 # Label for zap amount input field
-Amount_70f0 = Montant
+Amount_70f0da = Montant
 # Label for appearance settings section
-Appearance_4c7f = Apparence
+Appearance_4c7f77 = Apparence
 # Button to send message to Dave AI assistant
-Ask_b7f4 = Demander
+Ask_b7f4ce = Demander
 # Placeholder text for Dave AI input field
-Ask_dave_anything_33d1 = Demandez à Dave n'importe quoi...
+Ask_dave_anything_33d143 = Demandez à Dave n'importe quoi...
 # Profile banner URL field label
-Banner_52ef = Bannière
+Banner_52ef05 = Bannière
 # Beta version label
-BETA_8e5d = BETA
+BETA_8e5d71 = BETA
 # Broadcast the note to all connected relays
-Broadcast_fe43 = Diffusion
+Broadcast_fe432d = Diffusion
 # Broadcast the note only to local network relays
-Broadcast_Local_7e50 = Diffusion locale
+Broadcast_Local_7e50ce = Diffusion locale
 # Button label to cancel an action
-Cancel_ed3b = Annuler
+Cancel_ed3bc2 = Annuler
 # Label for cancel clear cache, Storage settings section
-Cancel_fd8b = Annuler
+Cancel_fd8ba7 = Annuler
 # Label for clear cache button, Storage settings section
-Clear_cache_dccb = Vider le cache
+Clear_cache_dccbdb = Vider le cache
 # Hover text for editable zap amount
-Click_to_edit_0414 = Cliquer pour modifier
+Click_to_edit_041404 = Cliquer pour modifier
 # Column title for note composition
-Compose_Note_c094 = Ecrire une note
+Compose_Note_c0946b = Ecrire une note
 # Label for configure relays, settings section
-Configure_relays_d156 = Configurer les relais
+Configure_relays_d15610 = Configurer les relais
 # Label for confirm clear cache, Storage settings section
-Confirm_9d9d = Confirmer
+Confirm_9d9d74 = Confirmer
 # Button label to confirm an action
-Confirm_f8a6 = Confirmer
+Confirm_f8a667 = Confirmer
 # Status label for connected relay
-Connected_f8cc = Connecté
+Connected_f8ccd1 = Connecté
 # Status label for connecting relay
-Connecting_6b7e = Connexion...
+Connecting_6b7ec3 = Connexion...
 # Title for contact list column
-Contact_List_f85a = Liste de contacts
+Contact_List_f85a7b = Liste de contacts
 # Column title for contact lists
-Contacts_7533 = Contacts
+Contacts_7533e6 = Contacts
 # Column title for last notes per contact
-Contacts__last_notes_3f84 = Contacts (dernières notes)
+Contacts__last_notes_3f84eb = Contacts (dernières notes)
 # Button label to copy logs
-Copy_a688 = Copier
+Copy_a688e8 = Copier
 # Button to copy media link to clipboard
-Copy_Link_dc7c = Copier le lien
+Copy_Link_dc7c2d = Copier le lien
 # Copy the unique note identifier to clipboard
-Copy_Note_ID_6b45 = Copier l'ID de la note
+Copy_Note_ID_6b4519 = Copier l'ID de la note
 # Copy the raw note data in JSON format to clipboard
-Copy_Note_JSON_9e4e = Copier le JSON de la note
+Copy_Note_JSON_9e4ebd = Copier le JSON de la note
 # Copy the author's public key to clipboard
-Copy_Pubkey_9cc4 = Copier la Pubkey
+Copy_Pubkey_9cc4e2 = Copier la Pubkey
 # Copy the text content of the note to clipboard
-Copy_Text_f81c = Copier le texte
+Copy_Text_f81c61 = Copier le texte
 # Relative time in days
-count_d_b9be = { $count }j
+count_d_b9bee1 = { $count }j
 # Relative time in hours
-count_h_3ecb = { $count }h
+count_h_3ecb5b = { $count }h
 # Relative time in minutes
-count_m_b41e = { $count }min
+count_m_b41e53 = { $count }min
 # Relative time in months
-count_mo_7aba = { $count }m
+count_mo_7aba49 = { $count }m
 # Relative time in seconds
-count_s_aa26 = { $count }s
+count_s_aa26bc = { $count }s
 # Relative time in weeks
-count_w_7468 = { $count }sem
+count_w_7468d2 = { $count }sem
 # Relative time in years
-count_y_9408 = { $count }a
+count_y_9408bc = { $count }a
 # Button to create a new account
-Create_Account_6994 = Créer un compte
+Create_Account_6994f5 = Créer un compte
 # Button label to create a new deck
-Create_Deck_16b7 = Créer un deck
+Create_Deck_16b7f2 = Créer un deck
 # Column title for custom timelines
-Custom_a69e = Personnaliser
+Custom_a69e8d = Personnaliser
 # Column title for zap amount customization
-Customize_Zap_Amount_cfc4 = Personnaliser le montant du Zap
+Customize_Zap_Amount_cfc445 = Personnaliser le montant du Zap
 # Column title for support page
-Damus_Support_27c0 = Assistance Damus
+Damus_Support_27c0c0 = Assistance Damus
 # Label for Theme Dark, Appearance settings section
-Dark_85fe = Sombre
+Dark_85feb6 = Sombre
 # Label for deck name input field
-Deck_name_cd32 = Nom du deck
+Deck_name_cd32b9 = Nom du deck
 # Label for decks section in side panel
-DECKS_1fad = DECKS
+DECKS_1fade2 = DECKS
 # Label for default zap amount input
-Default_amount_per_zap_399d = Montant par défaut pour un Zap :
+Default_amount_per_zap_399d11 = Montant par défaut pour un Zap :
 # Name of the default deck feed
-Default_Deck_fcca = Deck par défaut
+Default_Deck_fcca5f = Deck par défaut
 # Button label to delete a deck
-Delete_Deck_bb29 = Supprimer le deck
+Delete_Deck_bb298d = Supprimer le deck
 # Tooltip for deleting a column
-Delete_this_column_8d5a = Supprimer cette colonne
+Delete_this_column_8d5aab = Supprimer cette colonne
 # Button label to delete a wallet
-Delete_Wallet_d1d4 = Supprimer le portefeuille
+Delete_Wallet_d1d48e = Supprimer le portefeuille
 # Profile display name field label
-Display_name_f9d9 = Nom d'utilisateur
+Display_name_f9d965 = Nom d'utilisateur
 # Domain identification message
-domain___will_be_used_for_identification_b67e = "{ $domain }" sera utilisé pour l'identification
+domain___will_be_used_for_identification_b67ec0 = "{ $domain }" sera utilisé pour l'identification
 # Column title for editing deck
-Edit_Deck_4018 = Modifier le deck
+Edit_Deck_401827 = Modifier le deck
 # Button label to edit a deck
-Edit_Deck_fd93 = Modifier le deck
+Edit_Deck_fd939c = Modifier le deck
 # Button label to edit user profile
-Edit_Profile_49e6 = Modifier le profil
+Edit_Profile_49e658 = Modifier le profil
 # Column title for profile editing
-Edit_Profile_8ad4 = Modifier le profil
+Edit_Profile_8ad45b = Modifier le profil
 # Placeholder for hashtag input field
-Enter_the_desired_hashtags_here__for_multiple_space-separated_7a69 = Entrez les hashtags souhaités ici (séparez-les avec un espace)
+Enter_the_desired_hashtags_here__for_multiple_space-separated_7a695d = Entrez les hashtags souhaités ici (séparez-les avec un espace)
 # Placeholder for relay input field
-Enter_the_relay_here_1c8b = Entrer un relai ici
+Enter_the_relay_here_1c8bbe = Entrer un relai ici
 # Hint text to prompt entering the user's public key.
-Enter_the_user_s_key__npub__hex__nip05__here_650c = Entrer ici la clé de l'utilisateur (npub, hex, nip05)...
+Enter_the_user_s_key__npub__hex__nip05__here_650cf4 = Entrer ici la clé de l'utilisateur (npub, hex, nip05)...
 # Label for key input field. Key can be public key (npub), private key (nsec), or Nostr address (NIP-05).
-Enter_your_key_0fca = Entrez votre clé
+Enter_your_key_0fcaeb = Entrez votre clé
 # Instructions for entering Nostr credentials
-Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9 = Entrez votre clé publique (npub), votre adresse nostr (par exemple { $address }), ou votre clé privée (nsec). Vous devez entrer votre clé privée pour pouvoir poster, répondre, etc.
+Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9eb = Entrez votre clé publique (npub), votre adresse nostr (par exemple { $address }), ou votre clé privée (nsec). Vous devez entrer votre clé privée pour pouvoir poster, répondre, etc.
 # Label for find user button
-Find_User_bd12 = Trouver un utilisateur
+Find_User_bd120c = Trouver un utilisateur
 # Label for font size, Appearance settings section
-Font_size_dd73 = Taille du texte :
+Font_size_dd7325 = Taille du texte :
 # Title for hashtags column
-Hashtags_f8e0 = Hashtags
+Hashtags_f8e015 = Hashtags
 # Title for Home column
-Home_8c19 = Accueil
+Home_8c1918 = Accueil
 # Label for deck icon selection
-Icon_b0ab = Icone
+Icon_b0ab7c = Icone
 # Label for Image cache size, Storage settings section
-Image_cache_size_3004 = Taille du cache des images :
+Image_cache_size_3004a7 = Taille du cache des images :
 # Title for individual user column
-Individual_b776 = Individuel
+Individual_b77693 = Individuel
 # Error message for invalid zap amount
-Invalid_amount_6630 = Montant invalide
+Invalid_amount_66307c = Montant invalide
 # Error message for invalid key input
-Invalid_key_4726 = Clé non valide.
+Invalid_key_47265a = Clé non valide.
 # Error message for invalid Nostr Wallet Connect URI
-Invalid_NWC_URI_031b = Invalide NWC URI
+Invalid_NWC_URI_031bef = Invalide NWC URI
 # Zap amount button for 100000 sats. Abbreviated because the button is too small to display the full amount.
-k_100K_686c = 100K
+k_100K_686c79 = 100K
 # Zap amount button for 10000 sats. Abbreviated because the button is too small to display the full amount.
-k_10K_f7e6 = 10K
+k_10K_f7e6df = 10K
 # Zap amount button for 20000 sats. Abbreviated because the button is too small to display the full amount.
-k_20K_4977 = 20K
+k_20K_4977cb = 20K
 # Zap amount button for 50000 sats. Abbreviated because the button is too small to display the full amount.
-k_50K_c2dc = 50K
+k_50K_c2dc4b = 50K
 # Zap amount button for 5000 sats. Abbreviated because the button is too small to display the full amount.
-k_5K_f7e6 = 5K
+k_5K_f7e64e = 5K
 # Description for your notes column
-Keep_track_of_your_notes___replies_a334 = Gardez une trace de vos notes & réponses
+Keep_track_of_your_notes___replies_a33477 = Gardez une trace de vos notes & réponses
 # Label for language, Appearance settings section
-Language_e264 = Langue :
+Language_e264a6 = Langue :
 # Title for last note per user column
-Last_Note_per_User_17ad = Dernière note par utilisateur
+Last_Note_per_User_17ad2d = Dernière note par utilisateur
 # Label for Theme Light, Appearance settings section
-Light_7475 = Clair
+Light_74751a = Clair
 # Bitcoin Lightning network address field label
-Lightning_network_address__lud16_ea51 = Adresse réseau Lightning (lud16)
+Lightning_network_address__lud16_ea5104 = Adresse réseau Lightning (lud16)
 # Login page title
-Login_9eef = Se connecter
+Login_9eefa9 = Se connecter
 # Login button text
-Login_now___let_s_do_this_5630 = Se connecter maintenant - c'est parti !
+Login_now___let_s_do_this_563039 = Se connecter maintenant - c'est parti !
 # Text shown on blurred media from unfollowed users
-Media_from_someone_you_don_t_follow_5611 = Média d'une personne que vous ne suivez pas
+Media_from_someone_you_don_t_follow_56111b = Média d'une personne que vous ne suivez pas
 # Tooltip for moving a column
-Moves_this_column_to_another_position_0d4b = Déplace cette colonne vers une autre position
+Moves_this_column_to_another_position_0d4b4f = Déplace cette colonne vers une autre position
 # Title for the user's deck
-My_Deck_4ac5 = Mon deck
+My_Deck_4ac5a8 = Mon deck
 # Label asking if the user is new to Nostr. Underneath this label is a button to create an account.
-New_to_Nostr_a2fd = Nouveau sur Nostr ?
+New_to_Nostr_a2fd06 = Nouveau sur Nostr ?
 # NIP-05 identity field label
-Nostr_address__NIP-05_identity_74a2 = Adresse Nostr (NIP-05 identité)
+Nostr_address__NIP-05_identity_74a298 = Adresse Nostr (NIP-05 identité)
 # Default username when profile is not available
-nostrich_df29 = nostrich
+nostrich_df2951 = nostrich
 # Status label for disconnected relay
-Not_Connected_6292 = Non connecté
+Not_Connected_62928a = Non connecté
 # Link text for note references
-note_cad6 = note
+note_cad63d = note
 # Beta product warning message
-Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a671 = Notedeck est un produit en phase beta. Attendez-vous à des bugs et contactez-nous si vous rencontrez des problèmes.
+Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a6710e = Notedeck est un produit en phase beta. Attendez-vous à des bugs et contactez-nous si vous rencontrez des problèmes.
 # Filter label for notes only view
-Notes_03fb = Notes
+Notes_03fba8 = Notes
 # Label for notes-only filter
-Notes_60d2 = Notes
+Notes_60d293 = Notes
 # Filter label for notes and replies view
-Notes___Replies_1ec2 = Notes & Réponses
+Notes___Replies_1ec2f3 = Notes & Réponses
 # Label for notes and replies filter
-Notes___Replies_6e3b = Notes & Réponses
+Notes___Replies_6e3b3d = Notes & Réponses
 # Column title for notifications
-Notifications_d673 = Notifications
+Notifications_d67387 = Notifications
 # Title for notifications column
-Notifications_ef56 = Notifications
+Notifications_ef564b = Notifications
 # Relative time for very recent events (less than 3 seconds)
-now_2181 = maintenant
+now_2181ec = maintenant
 # Setting to turn on sorting replies so that the newest are shown first
-On_f412 = Activé
+On_f412d7 = Activé
 # Button label to open email client
-Open_Email_25e9 = Ouvrir Email
+Open_Email_25e932 = Ouvrir Email
 # Instruction to open email client
-Open_your_default_email_client_to_get_help_from_the_Damus_team_68dc = Ouvrez votre service d'email par défaut pour obtenir de l'aide de l'équipe Damus
+Open_your_default_email_client_to_get_help_from_the_Damus_team_68dce5 = Ouvrez votre service d'email par défaut pour obtenir de l'aide de l'équipe Damus
 # Label for others settings section
-Others_7267 = Autres
+Others_726757 = Autres
 # Placeholder text for NWC URI input
-Paste_your_NWC_URI_here_b471 = Collez ici votre NWC URI...
+Paste_your_NWC_URI_here_b47163 = Collez ici votre NWC URI...
 # Error message for missing deck name
-Please_create_a_name_for_the_deck_38e7 = Veuillez créer un nom pour le deck.
+Please_create_a_name_for_the_deck_38e717 = Veuillez créer un nom pour le deck.
 # Error message for missing deck name and icon
-Please_create_a_name_for_the_deck_and_select_an_icon_0add = Veuillez créer un nom pour le deck et sélectionner une icône.
+Please_create_a_name_for_the_deck_and_select_an_icon_0add35 = Veuillez créer un nom pour le deck et sélectionner une icône.
 # Error message for missing deck icon
-Please_select_an_icon_655b = Veuillez choisir une icône.
+Please_select_an_icon_655b5c = Veuillez choisir une icône.
 # Button label to post a note
-Post_now_8a49 = Publier maintenant
+Post_now_8a4984 = Publier maintenant
 # Instruction for copying logs
-Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322e = Cliquez sur le bouton ci-dessous pour copier vos données les plus récentes dans le presse-papiers de votre système. Collez-les ensuite dans votre courrier électronique.
+Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322eba = Cliquez sur le bouton ci-dessous pour copier vos données les plus récentes dans le presse-papiers de votre système. Collez-les ensuite dans votre courrier électronique.
 # Profile picture URL field label
-Profile_picture_81ff = Photo de profil
+Profile_picture_81ff30 = Photo de profil
 # Column title for quote composition
-Quote_475c = Citation
+Quote_475cc6 = Citation
 # Error message when quote note cannot be found
-Quote_of_unknown_note_e4f0 = Citation d'une note inconnue
+Quote_of_unknown_note_e4f086 = Citation d'une note inconnue
 # Label for read-only profile mode
-Read_only_82ff = En lecture seule
+Read_only_82ffd8 = En lecture seule
 # Column title for relay management
-Relays_9d89 = Relais
+Relays_9d892a = Relais
 # Label for relay list section
-Relays_ad5e = Relais
+Relays_ad5ef6 = Relais
 # Column title for reply composition
-Reply_3bf1 = Répondre
+Reply_3bf1ce = Répondre
 # Hover text for reply button
-Reply_to_this_note_f5de = Répondre à cette note
+Reply_to_this_note_f5de5b = Répondre à cette note
 # Error message when reply note cannot be found
-Reply_to_unknown_note_4401 = Répondre à la note inconnue
+Reply_to_unknown_note_44019e = Répondre à la note inconnue
 # Fallback template for replying to user
-replying_to__user_15ab = répondre à { $user }
+replying_to__user_15ab66 = répondre à { $user }
 # Template for replying to user in unknown thread
-replying_to__user__in_someone_s_thread_e148 = répondre à { $user } dans le fil de discussion
+replying_to__user__in_someone_s_thread_e14879 = répondre à { $user } dans le fil de discussion
 # Template for replying to note in different user's thread
-replying_to__user__s__note__in__thread_user__s__thread_daa8 = répondre à la { $note } de { $user } dans le { $thread } sur le { $thread_user }
+replying_to__user__s__note__in__thread_user__s__thread_daa8ed = répondre à la { $note } de { $user } dans le { $thread } sur le { $thread_user }
 # Template for replying to user's note
-replying_to__user__s__note_ccba = répondre à la { $note } de { $user }
+replying_to__user__s__note_ccba26 = répondre à la { $note } de { $user }
 # Template for replying to root thread
-replying_to__user__s__thread_444d = répondre dans le { $thread } de { $user }
+replying_to__user__s__thread_444dfb = répondre dans le { $thread } de { $user }
 # Fallback text when reply note is not found
-replying_to_a_note_e0bc = répondre à une note
+replying_to_a_note_e0bc5c = répondre à une note
 # Hover text for repost button
-Repost_this_note_8e56 = Republier cette note
+Repost_this_note_8e5605 = Republier cette note
 # Label for reposted notes
-Reposted_61c8 = Republier
+Reposted_61c83c = Republier
 # Label for reset note body font size, Appearance settings section
-Reset_4e60 = Réinitialiser
+Reset_4e6079 = Réinitialiser
 # Label for reset zoom level, Appearance settings section
-Reset_62d4 = Réinitialiser
+Reset_62d48f = Réinitialiser
 # Heading for support section
-Running_into_a_bug_1796 = Vous rencontrez un problème ?
+Running_into_a_bug_17962b = Vous rencontrez un problème ?
 # Label for satoshis (Bitcoin unit) for custom zap amount input field
-SATS_45d7 = SATS
+SATS_45d7cf = SATS
 # Unit label for satoshis (Bitcoin unit) for configuring default zap amount in wallet settings.
-sats_e5ec = sats
+sats_e5ece2 = sats
 # Button to save default zap amount
-Save_6f7c = Enregistrer
+Save_6f7c42 = Enregistrer
 # Button label to save profile changes
-Save_changes_00db = Enregistrer les modifications
+Save_changes_00db55 = Enregistrer les modifications
 # Column title for search page
-Search_c573 = Rechercher
+Search_c57363 = Rechercher
 # Placeholder for search notes input field
-Search_notes_42a6 = Rechercher des notes...
+Search_notes_42a638 = Rechercher des notes...
 # Search in progress message
-Searching_for___query_5d18 = Recherche par '{ $query }'
+Searching_for___query_5d18e6 = Recherche par '{ $query }'
 # Description for Home column
-See_notes_from_your_contacts_ac16 = Afficher les notes de vos contacts
+See_notes_from_your_contacts_ac1696 = Afficher les notes de vos contacts
 # Description for universe column
-See_the_whole_nostr_universe_7694 = Voir l'ensemble de l'univers nostr
+See_the_whole_nostr_universe_7694cb = Voir l'ensemble de l'univers nostr
 # Button label to send a zap
-Send_1ea4 = Envoyer
+Send_1ea468 = Envoyer
 # Column title for app settings
-Settings_7a4f = Paramètres
+Settings_7a4f18 = Paramètres
 # Description for last note per user column
-Show_the_last_note_for_each_user_from_a_list_50e7 = Afficher la dernière note de chaque utilisateur à partir d'une liste
+Show_the_last_note_for_each_user_from_a_list_50e722 = Afficher la dernière note de chaque utilisateur à partir d'une liste
 # Button label to sign out of account
-Sign_out_337b = Se déconnecter
+Sign_out_337b00 = Se déconnecter
 # Title for someone else's notes column
-Someone_else_s_Notes_7e5f = Notes de quelqu'un d'autre
+Someone_else_s_Notes_7e5fc1 = Notes de quelqu'un d'autre
 # Title for someone else's notifications column
-Someone_else_s_Notifications_82e6 = Notifications de quelqu'un d'autre
+Someone_else_s_Notifications_82e644 = Notifications de quelqu'un d'autre
 # Label for Sort replies newest first, others settings section
-Sort_replies_newest_first_b6c3 = Trier les réponses les plus récentes en premier :
+Sort_replies_newest_first_b6c303 = Trier les réponses les plus récentes en premier :
 # Description for contact list column
-Source_the_last_note_for_each_user_in_your_contact_list_e157 = Source de la dernière note pour chaque utilisateur de votre liste de contacts
+Source_the_last_note_for_each_user_in_your_contact_list_e1570f = Source de la dernière note pour chaque utilisateur de votre liste de contacts
 # Description for hashtags column
-Stay_up_to_date_with_a_certain_hashtag_88e3 = Restez informé sur un hashtag
+Stay_up_to_date_with_a_certain_hashtag_88e3ea = Restez informé sur un hashtag
 # Description for notifications column
-Stay_up_to_date_with_notifications_and_mentions_6f4e = Restez informé avec les notifications et les mentions
+Stay_up_to_date_with_notifications_and_mentions_6f4e31 = Restez informé avec les notifications et les mentions
 # Description for someone else's notes column
-Stay_up_to_date_with_someone_else_s_notes___replies_464c = Restez informé des notes et des réponses de quelqu'un d'autre
+Stay_up_to_date_with_someone_else_s_notes___replies_464c3c = Restez informé des notes et des réponses de quelqu'un d'autre
 # Description for someone else's notifications column
-Stay_up_to_date_with_someone_else_s_notifications_and_mentions_3473 = Restez informé des notifications et mentions de quelqu'un d'autre
+Stay_up_to_date_with_someone_else_s_notifications_and_mentions_347358 = Restez informé des notifications et mentions de quelqu'un d'autre
 # Description for individual user column
-Stay_up_to_date_with_someone_s_notes___replies_aa78 = Restez informé des notes et réponses de quelqu'un
+Stay_up_to_date_with_someone_s_notes___replies_aa7801 = Restez informé des notes et réponses de quelqu'un
 # Description for your notifications column
-Stay_up_to_date_with_your_notifications_and_mentions_e73e = Restez informé pour vos notifications et mentions
+Stay_up_to_date_with_your_notifications_and_mentions_e73ebf = Restez informé pour vos notifications et mentions
 # Step 1 label in support instructions
-Step_1_8656 = Etape 1
+Step_1_8656ee = Etape 1
 # Step 2 label in support instructions
-Step_2_d08d = Etape 2
+Step_2_d08dd4 = Etape 2
 # Label for storage settings section
-Storage_ed65 = Stockage
+Storage_ed658c = Stockage
 # Column title for subscribing to external user
-Subscribe_to_someone_else_s_notes_d1e9 = S'abonner aux notes de quelqu'un d'autre
+Subscribe_to_someone_else_s_notes_d1e927 = S'abonner aux notes de quelqu'un d'autre
 # Column title for subscribing to individual user
-Subscribe_to_someone_s_notes_b3c8 = S'abonner aux notes de quelqu'un
+Subscribe_to_someone_s_notes_b3c806 = S'abonner aux notes de quelqu'un
 # Support email address
-Support_email_44d9 = Adresse email de l'assistance :
+Support_email_44d972 = Adresse email de l'assistance :
 # Hover text for dark mode toggle button
-Switch_to_dark_mode_4dec = Passer en mode sombre
+Switch_to_dark_mode_4dec65 = Passer en mode sombre
 # Hover text for light mode toggle button
-Switch_to_light_mode_72ce = Passer en mode clair
+Switch_to_light_mode_72ce5d = Passer en mode clair
 # Button text to load blurred media
-Tap_to_Load_4b05 = Appuyer pour charger
+Tap_to_Load_4b05a5 = Appuyer pour charger
 # Message shown when Dave trial period has ended
-The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c7 = La période d'essai de l'assistant IA Dave Nostr est terminée :(. Merci de l'avoir testé ! Un Dave compatible-Zap sera bientôt disponible !
+The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c70a = La période d'essai de l'assistant IA Dave Nostr est terminée :(. Merci de l'avoir testé ! Un Dave compatible-Zap sera bientôt disponible !
 # Label for theme, Appearance settings section
-Theme_4aac = Thème :
+Theme_4aacbd = Thème :
 # Column title for note thread view
-Thread_0f20 = Fil
+Thread_0f2048 = Fil
 # Link text for thread references
-thread_ad1f = fil
+thread_ad1fab = fil
 # Title for universe column
-Universe_e01e = Universel
+Universe_e01e2c = Universel
 # Column title for universe feed
-Universe_ffaa = Universel
+Universe_ffaa75 = Universel
 # Checkbox label for using wallet only for current account
-Use_this_wallet_for_the_current_account_only_61dc = Utiliser ce portefeuille pour le compte actuel
+Use_this_wallet_for_the_current_account_only_61dc6a = Utiliser ce portefeuille pour le compte actuel
 # Username and domain identification message
-username___at___domain___will_be_used_for_identification_a4fd = "{ $username }" à "{ $domain }" sera utilisé pour l'identification
+username___at___domain___will_be_used_for_identification_a4fd76 = "{ $username }" à "{ $domain }" sera utilisé pour l'identification
 # Profile username field label
-Username_daa7 = Nom d'utilisateur
+Username_daa721 = Nom d'utilisateur
 # Label for view folder button, Storage settings section
-View_folder_9742 = Voir le dossier
+View_folder_9742ca = Voir le dossier
 # Column title for wallet management
-Wallet_5e50 = Portefeuille
+Wallet_5e5099 = Portefeuille
 # Hint for deck name input field
-We_recommend_short_names_083e = Nous recommandons des noms courts
+We_recommend_short_names_083e8e = Nous recommandons des noms courts
 # Profile website field label
-Website_7980 = Site web
+Website_798018 = Site web
 # Placeholder for note input field
-Write_a_banger_note_here_bad2 = Écrivez une note banger ici...
+Write_a_banger_note_here_bad23c = Écrivez une note banger ici...
 # Placeholder text for key input field
-Your_key_here_81bd = Votre clé ici...
+Your_key_here_81bdee = Votre clé ici...
 # Title for your notes column
-Your_Notes_f6db = Vos Notes
+Your_Notes_f6dbe4 = Vos Notes
 # Title for your notifications column
-Your_Notifications_080d = Vos notifications
+Your_Notifications_080d5f = Vos notifications
 # Heading for zap (tip) action
-Zap_16b4 = Zap
+Zap_16b444 = Zap
 # Hover text for zap button
-Zap_this_note_42b2 = Zap cette note
+Zap_this_note_42b210 = Zap cette note
 # Label for zoom level, Appearance settings section
-Zoom_Level_29a8 = Niveau de zoom :
+Zoom_Level_29a8c0 = Niveau de zoom :
 
 # Pluralized strings
 
 # Search results count
-Got__count__results_for___query_85fb =
+Got__count__results_for___query_85fbf5 =
     { $count ->
         [one] A obtenu { $count } pour '{ $query }'
        *[other] A obtenu { $count } pour '{ $query }'
@@ -6,404 +6,404 @@
 # Regular strings
 
 # Profile about/bio field label
-About_00c0 = 概要
+About_00c04c = 概要
 # Column title for account management
-Accounts_f018 = アカウント
+Accounts_f01844 = アカウント
 # Button label to add a relay
-Add_269d = 追加
+Add_269d81 = 追加
 # Label for add column button
-Add_47df = 追加
+Add_47dfb8 = 追加
 # Button label to add a different wallet
-Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d = このアカウントでのみ使用される別のウォレットを追加
+Add_a_different_wallet_that_will_only_be_used_for_this_account_de8d48 = このアカウントでのみ使用される別のウォレットを追加
 # Error message for missing wallet
-Add_a_wallet_to_continue_d170 = 続行するにはウォレットを追加してください
+Add_a_wallet_to_continue_d170b3 = 続行するにはウォレットを追加してください
 # Button label to add a new account
-Add_account_1cfc = アカウントを追加
+Add_account_1cfc87 = アカウントを追加
 # Column title for adding new account
-Add_Account_d06c = アカウントの追加
+Add_Account_d06c6a = アカウントの追加
 # Column title for adding algorithm column
-Add_Algo_Column_0d75 = アルゴカラムの追加
+Add_Algo_Column_0d751c = アルゴカラムの追加
 # Column title for adding new column
-Add_Column_c764 = カラムの追加
+Add_Column_c764c5 = カラムの追加
 # Column title for adding new deck
-Add_Deck_fabf = デッキの追加
+Add_Deck_fabf9d = デッキの追加
 # Column title for adding external notifications column
-Add_External_Notifications_Column_41ae = 外部通知カラムの追加
+Add_External_Notifications_Column_41aee7 = 外部通知カラムの追加
 # Column title for adding hashtag column
-Add_Hashtag_Column_ebf4 = ハッシュタグカラムの追加
+Add_Hashtag_Column_ebf4ac = ハッシュタグカラムの追加
 # Column title for adding last notes column
-Add_Last_Notes_Column_bbad = 最後の投稿カラムの追加
+Add_Last_Notes_Column_bbad64 = 最後の投稿カラムの追加
 # Column title for adding notifications column
-Add_Notifications_Column_79f8 = 外部通知カラムの追加
+Add_Notifications_Column_79f8e2 = 外部通知カラムの追加
 # Button label to add a relay
-Add_relay_269d = リレーを追加
+Add_relay_269d81 = リレーを追加
 # Button label to add a wallet
-Add_Wallet_d1be = ウォレットを追加
+Add_Wallet_d1bec0 = ウォレットを追加
 # Title for algorithmic feeds column
-Algo_2452 = アルゴ
+Algo_245216 = アルゴ
 # Description for algorithmic feeds column
-Algorithmic_feeds_to_aid_in_note_discovery_d344 = 投稿の発見に役立つアルゴリズムフィードです
+Algorithmic_feeds_to_aid_in_note_discovery_d34448 = 投稿の発見に役立つアルゴリズムフィードです
 # Label for zap amount input field
-Amount_70f0 = 金額
+Amount_70f0da = 金額
 # Label for appearance settings section
-Appearance_4c7f = 外観
+Appearance_4c7f77 = 外観
 # Button to send message to Dave AI assistant
-Ask_b7f4 = 質問
+Ask_b7f4ce = 質問
 # Placeholder text for Dave AI input field
-Ask_dave_anything_33d1 = Dave に何でも質問してみましょう…
+Ask_dave_anything_33d143 = Dave に何でも質問してみましょう…
 # Profile banner URL field label
-Banner_52ef = バナー
+Banner_52ef05 = バナー
 # Beta version label
-BETA_8e5d = ベータ
+BETA_8e5d71 = ベータ
 # Broadcast the note to all connected relays
-Broadcast_fe43 = ブロードキャスト
+Broadcast_fe432d = ブロードキャスト
 # Broadcast the note only to local network relays
-Broadcast_Local_7e50 = ローカルにブロードキャスト
+Broadcast_Local_7e50ce = ローカルにブロードキャスト
 # Button label to cancel an action
-Cancel_ed3b = キャンセル
+Cancel_ed3bc2 = キャンセル
 # Label for cancel clear cache, Storage settings section
-Cancel_fd8b = キャンセル
+Cancel_fd8ba7 = キャンセル
 # Label for clear cache button, Storage settings section
-Clear_cache_dccb = キャッシュを消去
+Clear_cache_dccbdb = キャッシュを消去
 # Hover text for editable zap amount
-Click_to_edit_0414 = クリックして編集
+Click_to_edit_041404 = クリックして編集
 # Column title for note composition
-Compose_Note_c094 = メモの作成
+Compose_Note_c0946b = メモの作成
 # Label for configure relays, settings section
-Configure_relays_d156 = リレーを設定
+Configure_relays_d15610 = リレーを設定
 # Label for confirm clear cache, Storage settings section
-Confirm_9d9d = 決定
+Confirm_9d9d74 = 決定
 # Button label to confirm an action
-Confirm_f8a6 = 決定
+Confirm_f8a667 = 決定
 # Status label for connected relay
-Connected_f8cc = 接続済
+Connected_f8ccd1 = 接続済
 # Status label for connecting relay
-Connecting_6b7e = 接続中…
+Connecting_6b7ec3 = 接続中…
 # Title for contact list column
-Contact_List_f85a = フォロイーリスト
+Contact_List_f85a7b = フォロイーリスト
 # Column title for contact lists
-Contacts_7533 = フォロー
+Contacts_7533e6 = フォロー
 # Column title for last notes per contact
-Contacts__last_notes_3f84 = フォロー (最後の投稿)
+Contacts__last_notes_3f84eb = フォロー (最後の投稿)
 # Button label to copy logs
-Copy_a688 = コピー
+Copy_a688e8 = コピー
 # Button to copy media link to clipboard
-Copy_Link_dc7c = リンクをコピー
+Copy_Link_dc7c2d = リンクをコピー
 # Copy the unique note identifier to clipboard
-Copy_Note_ID_6b45 = 投稿 ID をコピー
+Copy_Note_ID_6b4519 = 投稿 ID をコピー
 # Copy the raw note data in JSON format to clipboard
-Copy_Note_JSON_9e4e = 投稿の JSON をコピー
+Copy_Note_JSON_9e4ebd = 投稿の JSON をコピー
 # Copy the author's public key to clipboard
-Copy_Pubkey_9cc4 = 公開鍵をコピー
+Copy_Pubkey_9cc4e2 = 公開鍵をコピー
 # Copy the text content of the note to clipboard
-Copy_Text_f81c = テキストをコピー
+Copy_Text_f81c61 = テキストをコピー
 # Relative time in days
-count_d_b9be = { $count }日
+count_d_b9bee1 = { $count }日
 # Relative time in hours
-count_h_3ecb = { $count }時間
+count_h_3ecb5b = { $count }時間
 # Relative time in minutes
-count_m_b41e = { $count }分
+count_m_b41e53 = { $count }分
 # Relative time in months
-count_mo_7aba = { $count }ヶ月
+count_mo_7aba49 = { $count }ヶ月
 # Relative time in seconds
-count_s_aa26 = { $count }秒
+count_s_aa26bc = { $count }秒
 # Relative time in weeks
-count_w_7468 = { $count }週間
+count_w_7468d2 = { $count }週間
 # Relative time in years
-count_y_9408 = { $count }年
+count_y_9408bc = { $count }年
 # Button to create a new account
-Create_Account_6994 = アカウントを作成
+Create_Account_6994f5 = アカウントを作成
 # Button label to create a new deck
-Create_Deck_16b7 = デッキを作成
+Create_Deck_16b7f2 = デッキを作成
 # Column title for custom timelines
-Custom_a69e = カスタマイズ
+Custom_a69e8d = カスタマイズ
 # Column title for zap amount customization
-Customize_Zap_Amount_cfc4 = Zap 金額をカスタマイズ
+Customize_Zap_Amount_cfc445 = Zap 金額をカスタマイズ
 # Column title for support page
-Damus_Support_27c0 = Damus サポート
+Damus_Support_27c0c0 = Damus サポート
 # Label for Theme Dark, Appearance settings section
-Dark_85fe = ダーク
+Dark_85feb6 = ダーク
 # Label for deck name input field
-Deck_name_cd32 = デッキ名
+Deck_name_cd32b9 = デッキ名
 # Label for decks section in side panel
-DECKS_1fad = デッキ
+DECKS_1fade2 = デッキ
 # Label for default zap amount input
-Default_amount_per_zap_399d = Zap ごとのデフォルトの金額:
+Default_amount_per_zap_399d11 = Zap ごとのデフォルトの金額:
 # Name of the default deck feed
-Default_Deck_fcca = 既定のデッキ
+Default_Deck_fcca5f = 既定のデッキ
 # Button label to delete a deck
-Delete_Deck_bb29 = デッキを削除
+Delete_Deck_bb298d = デッキを削除
 # Tooltip for deleting a column
-Delete_this_column_8d5a = このカラムを削除します
+Delete_this_column_8d5aab = このカラムを削除します
 # Button label to delete a wallet
-Delete_Wallet_d1d4 = ウォレットを削除
+Delete_Wallet_d1d48e = ウォレットを削除
 # Profile display name field label
-Display_name_f9d9 = 表示名
+Display_name_f9d965 = 表示名
 # Domain identification message
-domain___will_be_used_for_identification_b67e = "{ $domain }" が識別に使用されます
+domain___will_be_used_for_identification_b67ec0 = "{ $domain }" が識別に使用されます
 # Column title for editing deck
-Edit_Deck_4018 = デッキの編集
+Edit_Deck_401827 = デッキの編集
 # Button label to edit a deck
-Edit_Deck_fd93 = デッキを編集
+Edit_Deck_fd939c = デッキを編集
 # Button label to edit user profile
-Edit_Profile_49e6 = プロファイルを編集
+Edit_Profile_49e658 = プロファイルを編集
 # Column title for profile editing
-Edit_Profile_8ad4 = プロファイルの編集
+Edit_Profile_8ad45b = プロファイルの編集
 # Placeholder for hashtag input field
-Enter_the_desired_hashtags_here__for_multiple_space-separated_7a69 = 必要なハッシュタグをここに入力してください (複数スペースで区切る場合)
+Enter_the_desired_hashtags_here__for_multiple_space-separated_7a695d = 必要なハッシュタグをここに入力してください (複数スペースで区切る場合)
 # Placeholder for relay input field
-Enter_the_relay_here_1c8b = ここにリレーを入力してください
+Enter_the_relay_here_1c8bbe = ここにリレーを入力してください
 # Hint text to prompt entering the user's public key.
-Enter_the_user_s_key__npub__hex__nip05__here_650c = ユーザーの鍵 (npub, hex, nip05) を入力してください...
+Enter_the_user_s_key__npub__hex__nip05__here_650cf4 = ユーザーの鍵 (npub, hex, nip05) を入力してください...
 # Label for key input field. Key can be public key (npub), private key (nsec), or Nostr address (NIP-05).
-Enter_your_key_0fca = 鍵を入力してください
+Enter_your_key_0fcaeb = 鍵を入力してください
 # Instructions for entering Nostr credentials
-Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9 = 公開鍵 (npub)、nostr アドレス (例: { $address })、秘密鍵 (nsec) を入力してください。 投稿、返信などを行うには秘密鍵を入力する必要があります。
+Enter_your_public_key__npub___nostr_address__e_g___address____or_private_key__nsec___You_must_enter_your_private_key_to_be_able_to_post__reply__etc_48e9eb = 公開鍵 (npub)、nostr アドレス (例: { $address })、秘密鍵 (nsec) を入力してください。 投稿、返信などを行うには秘密鍵を入力する必要があります。
 # Label for find user button
-Find_User_bd12 = ユーザーを探す
+Find_User_bd120c = ユーザーを探す
 # Label for font size, Appearance settings section
-Font_size_dd73 = フォントサイズ:
+Font_size_dd7325 = フォントサイズ:
 # Title for hashtags column
-Hashtags_f8e0 = ハッシュタグ
+Hashtags_f8e015 = ハッシュタグ
 # Title for Home column
-Home_8c19 = ホーム
+Home_8c1918 = ホーム
 # Label for deck icon selection
-Icon_b0ab = アイコン
+Icon_b0ab7c = アイコン
 # Label for Image cache size, Storage settings section
-Image_cache_size_3004 = 画像キャッシュのサイズ:
+Image_cache_size_3004a7 = 画像キャッシュのサイズ:
 # Title for individual user column
-Individual_b776 = 個人用
+Individual_b77693 = 個人用
 # Error message for invalid zap amount
-Invalid_amount_6630 = 無効な金額です
+Invalid_amount_66307c = 無効な金額です
 # Error message for invalid key input
-Invalid_key_4726 = 無効な鍵です。
+Invalid_key_47265a = 無効な鍵です。
 # Error message for invalid Nostr Wallet Connect URI
-Invalid_NWC_URI_031b = 無効な NWC URI です
+Invalid_NWC_URI_031bef = 無効な NWC URI です
 # Zap amount button for 100000 sats. Abbreviated because the button is too small to display the full amount.
-k_100K_686c = 100K
+k_100K_686c79 = 100K
 # Zap amount button for 10000 sats. Abbreviated because the button is too small to display the full amount.
-k_10K_f7e6 = 10K
+k_10K_f7e6df = 10K
 # Zap amount button for 20000 sats. Abbreviated because the button is too small to display the full amount.
-k_20K_4977 = 20K
+k_20K_4977cb = 20K
 # Zap amount button for 50000 sats. Abbreviated because the button is too small to display the full amount.
-k_50K_c2dc = 50K
+k_50K_c2dc4b = 50K
 # Zap amount button for 5000 sats. Abbreviated because the button is too small to display the full amount.
-k_5K_f7e6 = 5K
+k_5K_f7e64e = 5K
 # Description for your notes column
-Keep_track_of_your_notes___replies_a334 = 投稿と返信を記録します
+Keep_track_of_your_notes___replies_a33477 = 投稿と返信を記録します
 # Label for language, Appearance settings section
-Language_e264 = 言語:
+Language_e264a6 = 言語:
 # Title for last note per user column
-Last_Note_per_User_17ad = ユーザーごとの最後の投稿
+Last_Note_per_User_17ad2d = ユーザーごとの最後の投稿
 # Label for Theme Light, Appearance settings section
-Light_7475 = ライト
+Light_74751a = ライト
 # Bitcoin Lightning network address field label
-Lightning_network_address__lud16_ea51 = ライトニングネットワークアドレス (lud16)
+Lightning_network_address__lud16_ea5104 = ライトニングネットワークアドレス (lud16)
 # Login page title
-Login_9eef = ログイン
+Login_9eefa9 = ログイン
 # Login button text
-Login_now___let_s_do_this_5630 = 今すぐログイン — レッツゴー！
+Login_now___let_s_do_this_563039 = 今すぐログイン — レッツゴー！
 # Text shown on blurred media from unfollowed users
-Media_from_someone_you_don_t_follow_5611 = フォローしていない人のメディアです
+Media_from_someone_you_don_t_follow_56111b = フォローしていない人のメディアです
 # Tooltip for moving a column
-Moves_this_column_to_another_position_0d4b = このカラムを別の位置に移動します
+Moves_this_column_to_another_position_0d4b4f = このカラムを別の位置に移動します
 # Title for the user's deck
-My_Deck_4ac5 = あなたのデッキ
+My_Deck_4ac5a8 = あなたのデッキ
 # Label asking if the user is new to Nostr. Underneath this label is a button to create an account.
-New_to_Nostr_a2fd = Nostr は初めてですか？
+New_to_Nostr_a2fd06 = Nostr は初めてですか？
 # NIP-05 identity field label
-Nostr_address__NIP-05_identity_74a2 = Nostr アドレス (NIP-05)
+Nostr_address__NIP-05_identity_74a298 = Nostr アドレス (NIP-05)
 # Default username when profile is not available
-nostrich_df29 = ノス民
+nostrich_df2951 = ノス民
 # Status label for disconnected relay
-Not_Connected_6292 = 未接続
+Not_Connected_62928a = 未接続
 # Link text for note references
-note_cad6 = 投稿
+note_cad63d = 投稿
 # Beta product warning message
-Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a671 = Notedeck はベータ製品です。問題が発生した場合はサポートに問い合わせてください。
+Notedeck_is_a_beta_product__Expect_bugs_and_contact_us_when_you_run_into_issues_a6710e = Notedeck はベータ製品です。問題が発生した場合はサポートに問い合わせてください。
 # Filter label for notes only view
-Notes_03fb = 投稿
+Notes_03fba8 = 投稿
 # Label for notes-only filter
-Notes_60d2 = 投稿
+Notes_60d293 = 投稿
 # Filter label for notes and replies view
-Notes___Replies_1ec2 = 投稿 & 返信
+Notes___Replies_1ec2f3 = 投稿 & 返信
 # Label for notes and replies filter
-Notes___Replies_6e3b = 投稿 & 返信
+Notes___Replies_6e3b3d = 投稿 & 返信
 # Column title for notifications
-Notifications_d673 = 通知
+Notifications_d67387 = 通知
 # Title for notifications column
-Notifications_ef56 = 通知
+Notifications_ef564b = 通知
 # Relative time for very recent events (less than 3 seconds)
-now_2181 = たった今
+now_2181ec = たった今
 # Setting to turn on sorting replies so that the newest are shown first
-On_f412 = 有効
+On_f412d7 = 有効
 # Button label to open email client
-Open_Email_25e9 = メールを開く
+Open_Email_25e932 = メールを開く
 # Instruction to open email client
-Open_your_default_email_client_to_get_help_from_the_Damus_team_68dc = デフォルトのメールクライアントを開いて、Damus チームのヘルプを表示しましょう。
+Open_your_default_email_client_to_get_help_from_the_Damus_team_68dce5 = デフォルトのメールクライアントを開いて、Damus チームのヘルプを表示しましょう。
 # Label for others settings section
-Others_7267 = その他
+Others_726757 = その他
 # Placeholder text for NWC URI input
-Paste_your_NWC_URI_here_b471 = ここに NWC の URI を貼り付けてください...
+Paste_your_NWC_URI_here_b47163 = ここに NWC の URI を貼り付けてください...
 # Error message for missing deck name
-Please_create_a_name_for_the_deck_38e7 = デッキの名前を作成してください。
+Please_create_a_name_for_the_deck_38e717 = デッキの名前を作成してください。
 # Error message for missing deck name and icon
-Please_create_a_name_for_the_deck_and_select_an_icon_0add = デッキの名前を作成してアイコンを選択してください。
+Please_create_a_name_for_the_deck_and_select_an_icon_0add35 = デッキの名前を作成してアイコンを選択してください。
 # Error message for missing deck icon
-Please_select_an_icon_655b = アイコンを選択してください。
+Please_select_an_icon_655b5c = アイコンを選択してください。
 # Button label to post a note
-Post_now_8a49 = すぐに投稿
+Post_now_8a4984 = すぐに投稿
 # Instruction for copying logs
-Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322e = 下のボタンを押して、最新のログをシステムのクリップボードにコピーします。その後、メールに貼り付けてください。
+Press_the_button_below_to_copy_your_most_recent_logs_to_your_system_s_clipboard__Then_paste_it_into_your_email_322eba = 下のボタンを押して、最新のログをシステムのクリップボードにコピーします。その後、メールに貼り付けてください。
 # Profile picture URL field label
-Profile_picture_81ff = プロフィール写真
+Profile_picture_81ff30 = プロフィール写真
 # Column title for quote composition
-Quote_475c = 引用
+Quote_475cc6 = 引用
 # Error message when quote note cannot be found
-Quote_of_unknown_note_e4f0 = 不明な投稿の引用です
+Quote_of_unknown_note_e4f086 = 不明な投稿の引用です
 # Label for read-only profile mode
-Read_only_82ff = 読み取り専用
+Read_only_82ffd8 = 読み取り専用
 # Column title for relay management
-Relays_9d89 = リレー
+Relays_9d892a = リレー
 # Label for relay list section
-Relays_ad5e = リレー
+Relays_ad5ef6 = リレー
 # Column title for reply composition
-Reply_3bf1 = 返信
+Reply_3bf1ce = 返信
 # Hover text for reply button
-Reply_to_this_note_f5de = この投稿に返信
+Reply_to_this_note_f5de5b = この投稿に返信
 # Error message when reply note cannot be found
-Reply_to_unknown_note_4401 = 不明な投稿に返信しています
+Reply_to_unknown_note_44019e = 不明な投稿に返信しています
 # Fallback template for replying to user
-replying_to__user_15ab = { $user } に返信
+replying_to__user_15ab66 = { $user } に返信
 # Template for replying to user in unknown thread
-replying_to__user__in_someone_s_thread_e148 = 誰かのスレッドで { $user } に返信
+replying_to__user__in_someone_s_thread_e14879 = 誰かのスレッドで { $user } に返信
 # Template for replying to note in different user's thread
-replying_to__user__s__note__in__thread_user__s__thread_daa8 = { $user }の { $note } の { $thread_user }の { $thread } に返信
+replying_to__user__s__note__in__thread_user__s__thread_daa8ed = { $user }の { $note } の { $thread_user }の { $thread } に返信
 # Template for replying to user's note
-replying_to__user__s__note_ccba = { $user }の { $note } に返信
+replying_to__user__s__note_ccba26 = { $user }の { $note } に返信
 # Template for replying to root thread
-replying_to__user__s__thread_444d = { $user }の { $thread } に返信
+replying_to__user__s__thread_444dfb = { $user }の { $thread } に返信
 # Fallback text when reply note is not found
-replying_to_a_note_e0bc = 投稿に返信
+replying_to_a_note_e0bc5c = 投稿に返信
 # Hover text for repost button
-Repost_this_note_8e56 = このメモを再投稿
+Repost_this_note_8e5605 = このメモを再投稿
 # Label for reposted notes
-Reposted_61c8 = 再投稿
+Reposted_61c83c = 再投稿
 # Label for reset note body font size, Appearance settings section
-Reset_4e60 = リセット
+Reset_4e6079 = リセット
 # Label for reset zoom level, Appearance settings section
-Reset_62d4 = リセット
+Reset_62d48f = リセット
 # Heading for support section
-Running_into_a_bug_1796 = バグに遭遇しましたか？
+Running_into_a_bug_17962b = バグに遭遇しましたか？
 # Label for satoshis (Bitcoin unit) for custom zap amount input field
-SATS_45d7 = SATS
+SATS_45d7cf = SATS
 # Unit label for satoshis (Bitcoin unit) for configuring default zap amount in wallet settings.
-sats_e5ec = sats
+sats_e5ece2 = sats
 # Button to save default zap amount
-Save_6f7c = 保存
+Save_6f7c42 = 保存
 # Button label to save profile changes
-Save_changes_00db = 変更を保存
+Save_changes_00db55 = 変更を保存
 # Column title for search page
-Search_c573 = 検索
+Search_c57363 = 検索
 # Placeholder for search notes input field
-Search_notes_42a6 = 投稿を検索しましょう...
+Search_notes_42a638 = 投稿を検索しましょう...
 # Search in progress message
-Searching_for___query_5d18 = 「{ $query }」を検索中
+Searching_for___query_5d18e6 = 「{ $query }」を検索中
 # Description for Home column
-See_notes_from_your_contacts_ac16 = フォローしている人の投稿を表示
+See_notes_from_your_contacts_ac1696 = フォローしている人の投稿を表示
 # Description for universe column
-See_the_whole_nostr_universe_7694 = 全ユニバースを表示します
+See_the_whole_nostr_universe_7694cb = 全ユニバースを表示します
 # Button label to send a zap
-Send_1ea4 = 送信
+Send_1ea468 = 送信
 # Column title for app settings
-Settings_7a4f = 設定
+Settings_7a4f18 = 設定
 # Description for last note per user column
-Show_the_last_note_for_each_user_from_a_list_50e7 = 一覧から各ユーザーの最後の投稿を表示する
+Show_the_last_note_for_each_user_from_a_list_50e722 = 一覧から各ユーザーの最後の投稿を表示する
 # Button label to sign out of account
-Sign_out_337b = サインアウト
+Sign_out_337b00 = サインアウト
 # Title for someone else's notes column
-Someone_else_s_Notes_7e5f = 他の人の投稿
+Someone_else_s_Notes_7e5fc1 = 他の人の投稿
 # Title for someone else's notifications column
-Someone_else_s_Notifications_82e6 = 他の人の通知
+Someone_else_s_Notifications_82e644 = 他の人の通知
 # Label for Sort replies newest first, others settings section
-Sort_replies_newest_first_b6c3 = 最新の返信を最初に並べ替え:
+Sort_replies_newest_first_b6c303 = 最新の返信を最初に並べ替え:
 # Description for contact list column
-Source_the_last_note_for_each_user_in_your_contact_list_e157 = フォローリストにある各ユーザーの最後の投稿を取得します
+Source_the_last_note_for_each_user_in_your_contact_list_e1570f = フォローリストにある各ユーザーの最後の投稿を取得します
 # Description for hashtags column
-Stay_up_to_date_with_a_certain_hashtag_88e3 = 特定のハッシュタグで最新の情報を受け取ります
+Stay_up_to_date_with_a_certain_hashtag_88e3ea = 特定のハッシュタグで最新の情報を受け取ります
 # Description for notifications column
-Stay_up_to_date_with_notifications_and_mentions_6f4e = 通知とメンションの最新の情報を受け取ります
+Stay_up_to_date_with_notifications_and_mentions_6f4e31 = 通知とメンションの最新の情報を受け取ります
 # Description for someone else's notes column
-Stay_up_to_date_with_someone_else_s_notes___replies_464c = 他のユーザーの投稿と返信の最新の情報を受け取ります
+Stay_up_to_date_with_someone_else_s_notes___replies_464c3c = 他のユーザーの投稿と返信の最新の情報を受け取ります
 # Description for someone else's notifications column
-Stay_up_to_date_with_someone_else_s_notifications_and_mentions_3473 = 他のユーザーの投稿と返信の最新の情報を受け取ります
+Stay_up_to_date_with_someone_else_s_notifications_and_mentions_347358 = 他のユーザーの投稿と返信の最新の情報を受け取ります
 # Description for individual user column
-Stay_up_to_date_with_someone_s_notes___replies_aa78 = 投稿と返信の最新の情報を受け取ります
+Stay_up_to_date_with_someone_s_notes___replies_aa7801 = 投稿と返信の最新の情報を受け取ります
 # Description for your notifications column
-Stay_up_to_date_with_your_notifications_and_mentions_e73e = あなたの通知とメンションの最新の情報を受け取ります
+Stay_up_to_date_with_your_notifications_and_mentions_e73ebf = あなたの通知とメンションの最新の情報を受け取ります
 # Step 1 label in support instructions
-Step_1_8656 = ステップ 1
+Step_1_8656ee = ステップ 1
 # Step 2 label in support instructions
-Step_2_d08d = ステップ 2
+Step_2_d08dd4 = ステップ 2
 # Label for storage settings section
-Storage_ed65 = ストレージ
+Storage_ed658c = ストレージ
 # Column title for subscribing to external user
-Subscribe_to_someone_else_s_notes_d1e9 = 他のユーザー投稿の購読
+Subscribe_to_someone_else_s_notes_d1e927 = 他のユーザー投稿の購読
 # Column title for subscribing to individual user
-Subscribe_to_someone_s_notes_b3c8 = 投稿の購読
+Subscribe_to_someone_s_notes_b3c806 = 投稿の購読
 # Support email address
-Support_email_44d9 = サポートメール:
+Support_email_44d972 = サポートメール:
 # Hover text for dark mode toggle button
-Switch_to_dark_mode_4dec = ダークモードに切り替える
+Switch_to_dark_mode_4dec65 = ダークモードに切り替える
 # Hover text for light mode toggle button
-Switch_to_light_mode_72ce = ライトモードに切り替える
+Switch_to_light_mode_72ce5d = ライトモードに切り替える
 # Button text to load blurred media
-Tap_to_Load_4b05 = タップして読み込む
+Tap_to_Load_4b05a5 = タップして読み込む
 # Message shown when Dave trial period has ended
-The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c7 = Dave Nostr AI アシスタントトライアルが終了しました: (テストしていただきありがとうございます! Zap 対応デイブは近日公開予定です!
+The_Dave_Nostr_AI_assistant_trial_has_ended_____Thanks_for_testing__Zap-enabled_Dave_coming_soon_c6c70a = Dave Nostr AI アシスタントトライアルが終了しました: (テストしていただきありがとうございます! Zap 対応デイブは近日公開予定です!
 # Label for theme, Appearance settings section
-Theme_4aac = テーマ:
+Theme_4aacbd = テーマ:
 # Column title for note thread view
-Thread_0f20 = スレッド
+Thread_0f2048 = スレッド
 # Link text for thread references
-thread_ad1f = スレッド
+thread_ad1fab = スレッド
 # Title for universe column
-Universe_e01e = ユニバース
+Universe_e01e2c = ユニバース
 # Column title for universe feed
-Universe_ffaa = ユニバース
+Universe_ffaa75 = ユニバース
 # Checkbox label for using wallet only for current account
-Use_this_wallet_for_the_current_account_only_61dc = このウォレットを現在のアカウントにのみ使用する
+Use_this_wallet_for_the_current_account_only_61dc6a = このウォレットを現在のアカウントにのみ使用する
 # Username and domain identification message
-username___at___domain___will_be_used_for_identification_a4fd = "{ $domain }" の "{ $username }" が識別に使用されます
+username___at___domain___will_be_used_for_identification_a4fd76 = "{ $domain }" の "{ $username }" が識別に使用されます
 # Profile username field label
-Username_daa7 = ユーザー名
+Username_daa721 = ユーザー名
 # Label for view folder button, Storage settings section
-View_folder_9742 = フォルダを表示
+View_folder_9742ca = フォルダを表示
 # Column title for wallet management
-Wallet_5e50 = ウォレット
+Wallet_5e5099 = ウォレット
 # Hint for deck name input field
-We_recommend_short_names_083e = 短い名前を推奨しています
+We_recommend_short_names_083e8e = 短い名前を推奨しています
 # Profile website field label
-Website_7980 = Web サイト
+Website_798018 = Web サイト
 # Placeholder for note input field
-Write_a_banger_note_here_bad2 = アツい一言をどうぞ...
+Write_a_banger_note_here_bad23c = アツい一言をどうぞ...
 # Placeholder text for key input field
-Your_key_here_81bd = ここに鍵を入力...
+Your_key_here_81bdee = ここに鍵を入力...
 # Title for your notes column
-Your_Notes_f6db = 投稿
+Your_Notes_f6dbe4 = 投稿
 # Title for your notifications column
-Your_Notifications_080d = 通知
+Your_Notifications_080d5f = 通知
 # Heading for zap (tip) action
-Zap_16b4 = Zap
+Zap_16b444 = Zap
 # Hover text for zap button
-Zap_this_note_42b2 = この投稿に Zap
+Zap_this_note_42b210 = この投稿に Zap
 # Label for zoom level, Appearance settings section
-Zoom_Level_29a8 = 拡大率:
+Zoom_Level_29a8c0 = 拡大率:
 
 # Pluralized strings
 
 # Search results count
-Got__count__results_for___query_85fb =
+Got__count__results_for___query_85fbf5 =
     { $count ->
         [one] { $query } の結果を '{ $count }' 件取得しました
        *[other] ' { $query } の結果を '{ $count }' 件取得しました
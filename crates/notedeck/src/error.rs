@@ -1,4 +1,4 @@
-use std::io;
+use std::{io, path::PathBuf};
 
 /// App related errors
 #[derive(thiserror::Error, Debug)]
@@ -9,6 +9,9 @@ pub enum Error {
     #[error("io error: {0}")]
     Io(#[from] io::Error),
 
+    #[error("file not found: {0}")]
+    NotFound(PathBuf),
+
     #[error("subscription error: {0}")]
     SubscriptionError(SubscriptionError),
 
@@ -24,6 +27,12 @@ pub enum Error {
     #[error("generic error: {0}")]
     Generic(String),
 
+    #[error("checksum mismatch: {0}")]
+    ChecksumMismatch(String),
+
+    #[error("file too large: {0} is over {1} bytes")]
+    FileTooLarge(PathBuf, u64),
+
     #[error("zaps error: {0}")]
     Zap(#[from] ZapError),
 }
@@ -0,0 +1,92 @@
+//! Locale aware comparison of strings, for sorting user facing lists
+
+use std::cmp::Ordering;
+
+use unic_langid::LanguageIdentifier;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// Letters a locale sorts as letters of their own after z, rather than as
+/// accented variants of their base letter, in alphabet order
+fn tailored_letters(locale: &LanguageIdentifier) -> &'static [char] {
+    match locale.language.as_str() {
+        "sv" | "fi" => &['å', 'ä', 'ö'],
+        "da" | "nb" | "nn" | "no" => &['æ', 'ø', 'å'],
+        _ => &[],
+    }
+}
+
+/// Compare strings the way `locale` sorts them: by their letters first, so "Äpfel"
+/// sorts next to "Apfel" rather than after "Zebra", then unaccented before
+/// accented, then lowercase before uppercase. Scripts without an alphabet order,
+/// like CJK, are compared by code point
+pub(crate) fn compare(locale: &LanguageIdentifier, a: &str, b: &str) -> Ordering {
+    let tailored = tailored_letters(locale);
+
+    primary_weights(a, tailored)
+        .cmp(&primary_weights(b, tailored))
+        .then_with(|| accent_key(a).cmp(&accent_key(b)))
+        .then_with(|| case_key(a).cmp(&case_key(b)))
+        .then_with(|| a.cmp(b))
+}
+
+/// The base letters of `s`, ignoring case and accents. Tailored letters weigh
+/// more than any code point
+fn primary_weights(s: &str, tailored: &[char]) -> Vec<u32> {
+    let mut weights = Vec::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if let Some(i) = tailored.iter().position(|t| *t == c) {
+            weights.push(char::MAX as u32 + 1 + i as u32);
+            continue;
+        }
+
+        weights.extend(
+            std::iter::once(c)
+                .nfd()
+                .filter(|c| !is_combining_mark(*c))
+                .map(u32::from),
+        );
+    }
+
+    weights
+}
+
+fn accent_key(s: &str) -> String {
+    s.to_lowercase().nfd().collect()
+}
+
+fn case_key(s: &str) -> Vec<bool> {
+    s.chars().map(char::is_uppercase).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    fn sorted(locale: &LanguageIdentifier, words: &[&str]) -> Vec<String> {
+        let mut words: Vec<String> = words.iter().map(|word| word.to_string()).collect();
+        words.sort_by(|a, b| compare(locale, a, b));
+        words
+    }
+
+    #[test]
+    fn test_compare() {
+        let words = ["Zebra", "Äpfel", "apfel", "Apfel", "Öl", "Ofen"];
+
+        assert_eq!(
+            sorted(&langid!("de"), &words),
+            ["apfel", "Apfel", "Äpfel", "Ofen", "Öl", "Zebra"]
+        );
+        // å, ä and ö come after z in Swedish
+        assert_eq!(
+            sorted(&langid!("sv"), &words),
+            ["apfel", "Apfel", "Ofen", "Zebra", "Äpfel", "Öl"]
+        );
+
+        assert_eq!(
+            compare(&langid!("en-US"), "résumé", "resume"),
+            Ordering::Greater
+        );
+        assert_eq!(compare(&langid!("en-US"), "same", "same"), Ordering::Equal);
+    }
+}
@@ -10,6 +10,9 @@ pub enum IntlError {
     #[error("message has no value: {0}")]
     NoValue(IntlKeyBuf),
 
+    #[error("message {0} has no attribute: {1}")]
+    NoAttribute(IntlKeyBuf, String),
+
     #[error("Locale({0}) parse error: {1}")]
     LocaleParse(LanguageIdentifier, String),
 
@@ -21,4 +24,16 @@ pub enum IntlError {
 
     #[error("Bundle for '{0}' is not available")]
     NoBundle(LanguageIdentifier),
+
+    #[error("FTL for '{0}' has errors: {1:?}")]
+    ParseErrors(LanguageIdentifier, Vec<String>),
+
+    #[error("message {0} failed to format: {1:?}")]
+    FormatErrors(IntlKeyBuf, Vec<String>),
+
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("storage error: {0}")]
+    Storage(#[from] crate::Error),
 }
@@ -0,0 +1,210 @@
+//! Locale aware formatting of numbers and dates inside fluent bundles
+
+use chrono::{Local, NaiveDateTime, TimeZone};
+use fluent::{FluentArgs, FluentValue};
+use intl_memoizer::{concurrent::IntlLangMemoizer, Memoizable};
+use unic_langid::LanguageIdentifier;
+
+/// Formatting that replaces the defaults of a locale, see
+/// [`crate::Localization::set_format_override`]. Fields left as `None` keep the
+/// locale's default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub decimal_separator: Option<char>,
+    pub group_separator: Option<char>,
+    pub date_style: Option<DateStyle>,
+}
+
+/// How dates are written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateStyle {
+    /// `2025-03-09 14:05`
+    Iso,
+    /// chrono strftime patterns for the date and the time, e.g. `%d %b %Y` and `%H:%M`
+    Custom { date: String, time: String },
+}
+
+/// The symbols a locale writes numbers with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct NumberSymbols {
+    pub decimal: char,
+    pub group: char,
+}
+
+impl NumberSymbols {
+    pub fn for_locale(locale: &LanguageIdentifier) -> Self {
+        let region = locale.region.as_ref().map(|region| region.as_str());
+        let (decimal, group) = match (locale.language.as_str(), region) {
+            ("de", _) | ("es", Some("ES")) | ("pt", Some("BR")) => (',', '.'),
+            // narrow no-break space
+            ("fr", _) => (',', '\u{202F}'),
+            // no-break space
+            ("pt", _) => (',', '\u{00A0}'),
+            _ => ('.', ','),
+        };
+
+        NumberSymbols { decimal, group }
+    }
+
+    /// These symbols with any set in `options` replacing them
+    pub fn with_options(self, options: Option<&FormatOptions>) -> Self {
+        let Some(options) = options else {
+            return self;
+        };
+
+        NumberSymbols {
+            decimal: options.decimal_separator.unwrap_or(self.decimal),
+            group: options.group_separator.unwrap_or(self.group),
+        }
+    }
+
+    /// Rewrites a plain number like `-1234567.5` with these symbols
+    pub fn format(&self, number: &str, use_grouping: bool) -> String {
+        let (sign, digits) = match number.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", number),
+        };
+        let (integer, fraction) = match digits.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (digits, None),
+        };
+
+        let mut formatted = String::with_capacity(number.len() + integer.len() / 3);
+        formatted.push_str(sign);
+        for (i, c) in integer.chars().enumerate() {
+            if use_grouping && i > 0 && (integer.len() - i) % 3 == 0 {
+                formatted.push(self.group);
+            }
+            formatted.push(c);
+        }
+
+        if let Some(fraction) = fraction {
+            formatted.push(self.decimal);
+            formatted.push_str(fraction);
+        }
+
+        formatted
+    }
+}
+
+impl Memoizable for NumberSymbols {
+    type Args = ();
+    type Error = ();
+
+    fn construct(lang: LanguageIdentifier, _args: ()) -> Result<Self, ()> {
+        Ok(NumberSymbols::for_locale(&lang))
+    }
+}
+
+/// Bundle formatter that writes numbers with the symbols of the bundle's locale
+pub(crate) fn format_value(value: &FluentValue<'_>, intls: &IntlLangMemoizer) -> Option<String> {
+    let FluentValue::Number(number) = value else {
+        return None;
+    };
+
+    intls
+        .with_try_get::<NumberSymbols, _, _>((), |symbols| {
+            symbols.format(&number.as_string(), number.options.use_grouping)
+        })
+        .ok()
+}
+
+/// The `DATETIME` function for `locale`. Takes a unix timestamp in seconds and
+/// writes it as a numeric date in the local timezone, with the time as well when
+/// given `timeStyle: "short"`. `date_style` replaces the locale's date format
+pub(crate) fn datetime_function(
+    locale: LanguageIdentifier,
+    date_style: Option<DateStyle>,
+) -> impl for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a> + Send + Sync + 'static {
+    move |positional, named| {
+        let Some(FluentValue::Number(timestamp)) = positional.first() else {
+            return FluentValue::Error;
+        };
+
+        let Some(datetime) = Local.timestamp_opt(timestamp.value as i64, 0).single() else {
+            return FluentValue::Error;
+        };
+
+        let with_time = matches!(
+            named.get("timeStyle"),
+            Some(FluentValue::String(style)) if style != "none"
+        );
+
+        FluentValue::String(
+            format_datetime(
+                &locale,
+                &datetime.naive_local(),
+                with_time,
+                date_style.as_ref(),
+            )
+            .into(),
+        )
+    }
+}
+
+pub(crate) fn format_datetime(
+    locale: &LanguageIdentifier,
+    datetime: &NaiveDateTime,
+    with_time: bool,
+    date_style: Option<&DateStyle>,
+) -> String {
+    let (date, time) = match date_style {
+        Some(DateStyle::Iso) => ("%Y-%m-%d", "%H:%M"),
+        Some(DateStyle::Custom { date, time }) => (date.as_str(), time.as_str()),
+        None => match locale.language.as_str() {
+            "en" => ("%m/%d/%Y", "%-I:%M %p"),
+            "de" => ("%d.%m.%Y", "%H:%M"),
+            "ja" | "zh" => ("%Y/%m/%d", "%H:%M"),
+            _ => ("%d/%m/%Y", "%H:%M"),
+        },
+    };
+
+    if with_time {
+        datetime.format(&format!("{date} {time}")).to_string()
+    } else {
+        datetime.format(date).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use unic_langid::langid;
+
+    #[test]
+    fn test_number_symbols() {
+        let en_us = NumberSymbols::for_locale(&langid!("en-US"));
+        assert_eq!(en_us.format("-1234567.5", true), "-1,234,567.5");
+        assert_eq!(en_us.format("123", true), "123");
+        assert_eq!(en_us.format("1234", false), "1234");
+
+        let de = NumberSymbols::for_locale(&langid!("de"));
+        assert_eq!(de.format("1234567.5", true), "1.234.567,5");
+    }
+
+    #[test]
+    fn test_format_datetime() {
+        let datetime = NaiveDate::from_ymd_opt(2025, 3, 9)
+            .unwrap()
+            .and_hms_opt(14, 5, 0)
+            .unwrap();
+
+        assert_eq!(
+            format_datetime(&langid!("en-US"), &datetime, true, None),
+            "03/09/2025 2:05 PM"
+        );
+        assert_eq!(
+            format_datetime(&langid!("de"), &datetime, false, None),
+            "09.03.2025"
+        );
+        assert_eq!(
+            format_datetime(&langid!("ja"), &datetime, false, None),
+            "2025/03/09"
+        );
+        assert_eq!(
+            format_datetime(&langid!("en-US"), &datetime, true, Some(&DateStyle::Iso)),
+            "2025-03-09 14:05"
+        );
+    }
+}
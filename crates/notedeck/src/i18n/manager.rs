@@ -1,9 +1,24 @@
+use super::collate;
+use super::format::{self, FormatOptions};
 use super::{IntlError, IntlKey, IntlKeyBuf};
-use fluent::{FluentArgs, FluentBundle, FluentResource};
+use crate::storage::{write_file, DataPath, DataPathType, Directory};
+use crate::tr;
+use fluent::concurrent::FluentBundle;
+use fluent::{FluentArgs, FluentError, FluentResource, FluentValue};
 use fluent_langneg::negotiate_languages;
+use fluent_syntax::ast;
 use std::borrow::Cow;
-use std::collections::HashMap;
-use unic_langid::{langid, LanguageIdentifier};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::Arc;
+use unic_langid::{langid, CharacterDirection, LanguageIdentifier};
+use unicode_normalization::UnicodeNormalization;
 
 const EN_US: LanguageIdentifier = langid!("en-US");
 const EN_XA: LanguageIdentifier = langid!("en-XA");
@@ -19,6 +34,24 @@ const ZH_CN: LanguageIdentifier = langid!("zh-CN");
 const ZH_TW: LanguageIdentifier = langid!("zh-TW");
 const NUM_FTLS: usize = 12;
 
+/// Accented filler appended to en-XA strings when expanding them
+const PSEUDOLOCALE_FILLER: &str = " ŀőŗêɱ ïƥšûɱ ðőŀőŗ šïţ àɱêţ";
+
+/// Bytes of the comment hash appended to normalized keys. Must match
+/// `KEY_HASH_BYTES` in scripts/export_source_strings.py
+const KEY_HASH_BYTES: usize = 3;
+
+/// File in the settings directory the selected locale is persisted to
+const LOCALE_FILE: &str = "locale";
+
+/// Unicode bidi isolation marks, the same ones fluent puts around placeables
+const FIRST_STRONG_ISOLATE: char = '\u{2068}';
+const RIGHT_TO_LEFT_ISOLATE: char = '\u{2067}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// The "Combining Diacritical Marks" block, i.e. accents on latin, greek and cyrillic letters
+const COMBINING_DIACRITICS: std::ops::RangeInclusive<char> = '\u{0300}'..='\u{036f}';
+
 const EN_US_NATIVE_NAME: &str = "English (US)";
 const EN_XA_NATIVE_NAME: &str = "Éñglísh (Pséúdólóçàlé)";
 const DE_NATIVE_NAME: &str = "Deutsch";
@@ -32,6 +65,27 @@ const TH_NATIVE_NAME: &str = "ภาษาไทย";
 const ZH_CN_NATIVE_NAME: &str = "简体中文";
 const ZH_TW_NATIVE_NAME: &str = "繁體中文";
 
+/// Pairs of locales whose speakers generally accept the other as a fallback.
+/// zh-CN/zh-TW use different scripts (simplified vs traditional), so that pair is
+/// only a rough fallback, but still better than falling all the way back to English.
+const RELATED_LOCALES: [(LanguageIdentifier, LanguageIdentifier); 3] =
+    [(ES_419, ES_ES), (PT_BR, PT_PT), (ZH_CN, ZH_TW)];
+
+/// Regional variants of a language that negotiate to one of our locales when the
+/// region itself isn't available, e.g. es-MX to es-419. Regions are matched
+/// against the region subtag of the preferred locale
+const REGION_GROUPS: [(&str, &[&str], LanguageIdentifier); 2] = [
+    (
+        "es",
+        &[
+            "419", "AR", "BO", "BR", "BZ", "CL", "CO", "CR", "CU", "DO", "EC", "GT", "HN", "MX",
+            "NI", "PA", "PE", "PR", "PY", "SV", "US", "UY", "VE",
+        ],
+        ES_419,
+    ),
+    ("es", &["AD", "ES", "GQ"], ES_ES),
+];
+
 struct StaticBundle {
     identifier: LanguageIdentifier,
     ftl: &'static str,
@@ -90,6 +144,15 @@ const FTLS: [StaticBundle; NUM_FTLS] = [
 
 type Bundle = FluentBundle<FluentResource>;
 
+/// A bundle built from ftl, along with what went wrong building it
+struct BuiltBundle {
+    bundle: Bundle,
+    /// Errors parsing the ftl or adding it to the bundle
+    errors: Vec<String>,
+    /// Message and term ids defined more than once, see [`Localization::duplicate_ids`]
+    duplicate_ids: Vec<String>,
+}
+
 /// Manages localization resources and provides localized strings
 pub struct Localization {
     /// Current locale
@@ -98,19 +161,77 @@ pub struct Localization {
     available_locales: Vec<LanguageIdentifier>,
     /// Fallback locale
     fallback_locale: LanguageIdentifier,
+    /// Locales tried in order when a message is missing from a locale. Locales
+    /// without an entry only fall back to `fallback_locale`
+    fallback_chain: HashMap<LanguageIdentifier, Vec<LanguageIdentifier>>,
     /// Native names for locales
     locale_native_names: HashMap<LanguageIdentifier, String>,
 
-    /// Cached string results per locale (only for strings without arguments)
-    string_cache: HashMap<LanguageIdentifier, HashMap<String, String>>,
-    /// Cached normalized keys
-    normalized_key_cache: HashMap<String, IntlKeyBuf>,
+    /// Cached string results per locale. Strings with arguments are only cached
+    /// when `cache_strings_with_args` is set
+    string_cache: HashMap<LanguageIdentifier, StringCache>,
+    cache_strings_with_args: bool,
+    /// String cache lookups since the cache was last cleared, see [`CacheStats`]
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    /// Cached normalized keys by source string, then by comment, since the comment
+    /// is hashed into the key
+    normalized_key_cache: HashMap<String, HashMap<String, IntlKeyBuf>>,
+    /// Bytes of the comment hash appended to normalized keys
+    key_hash_bytes: usize,
+    /// Whether normalized keys keep non-ASCII letters, see [`fixup_key_transliterated`]
+    transliterate_keys: bool,
+    /// The source string and comment each normalized key was made from, to catch
+    /// two different strings colliding on the same key
+    #[cfg(debug_assertions)]
+    normalized_key_sources: HashMap<String, (String, String)>,
     /// Bundles
     bundles: HashMap<LanguageIdentifier, Bundle>,
+    /// Errors from parsing the ftl of each loaded bundle that had any
+    bundle_errors: HashMap<LanguageIdentifier, Vec<String>>,
+    /// Ids defined more than once in the ftl of each loaded bundle that had any
+    duplicate_ids: HashMap<LanguageIdentifier, Vec<String>>,
+    /// Ftl sources loaded at runtime, used instead of the baked in ones
+    runtime_ftls: HashMap<LanguageIdentifier, String>,
+    /// Small ftl sources layered over a locale's bundle, see [`Localization::add_override`]
+    override_ftls: HashMap<LanguageIdentifier, Vec<String>>,
 
     use_isolating: bool,
+
+    /// How much longer en-XA strings are made, see [`Localization::set_pseudolocale_expansion`]
+    pseudolocale_expansion: f32,
+
+    /// Formatting replacing the defaults of a locale, see
+    /// [`Localization::set_format_override`]
+    format_overrides: HashMap<LanguageIdentifier, FormatOptions>,
+
+    /// Functions callable from ftl, registered on every bundle as it loads
+    functions: Vec<(String, FluentFunction)>,
+
+    /// Called each time a bundle finishes loading
+    bundle_loaded_observer: Option<BundleLoadedObserver>,
+
+    /// Formats strings that have no translation, see [`Localization::set_missing_placeholder`]
+    missing_placeholder: Option<MissingPlaceholder>,
+
+    /// The argument names supplied for each message looked up this session
+    supplied_variables: HashMap<String, HashSet<String>>,
+
+    /// Missing keys that were already warned about, per locale
+    warned_missing_keys: HashMap<LanguageIdentifier, HashSet<String>>,
+
+    /// How the locale was last negotiated, see [`Localization::negotiation_report`]
+    negotiation_report: NegotiationReport,
+    /// Whether negotiation uses preferred locales only as given, see
+    /// [`Localization::set_strict_negotiation`]
+    strict_negotiation: bool,
 }
 
+type BundleLoadedObserver = Box<dyn FnMut(&LanguageIdentifier) + Send + Sync>;
+type MissingPlaceholder = Box<dyn Fn(&str) -> String + Send + Sync>;
+type FluentFunction =
+    Arc<dyn for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a> + Send + Sync>;
+
 impl Default for Localization {
     fn default() -> Self {
         // Default to English (US)
@@ -148,15 +269,52 @@ impl Default for Localization {
             (ZH_TW, ZH_TW_NATIVE_NAME.to_owned()),
         ]);
 
+        let fallback_chain = RELATED_LOCALES
+            .iter()
+            .flat_map(|(a, b)| {
+                [
+                    (a.clone(), vec![b.clone(), fallback_locale.clone()]),
+                    (b.clone(), vec![a.clone(), fallback_locale.clone()]),
+                ]
+            })
+            .collect();
+
         Self {
             current_locale: default_locale.to_owned(),
             available_locales,
             fallback_locale,
+            fallback_chain,
             locale_native_names,
             use_isolating: true,
+            pseudolocale_expansion: 1.0,
+            format_overrides: HashMap::new(),
+            functions: Vec::new(),
             normalized_key_cache: HashMap::new(),
+            key_hash_bytes: KEY_HASH_BYTES,
+            transliterate_keys: false,
+            #[cfg(debug_assertions)]
+            normalized_key_sources: HashMap::new(),
             string_cache: HashMap::new(),
+            cache_strings_with_args: false,
+            cache_hits: AtomicU64::new(0),
+            cache_misses: AtomicU64::new(0),
             bundles: HashMap::new(),
+            bundle_errors: HashMap::new(),
+            duplicate_ids: HashMap::new(),
+            runtime_ftls: HashMap::new(),
+            override_ftls: HashMap::new(),
+            bundle_loaded_observer: None,
+            missing_placeholder: None,
+            supplied_variables: HashMap::new(),
+            warned_missing_keys: HashMap::new(),
+            negotiation_report: NegotiationReport {
+                requested: Vec::new(),
+                substitutions: Vec::new(),
+                fallback: default_locale.to_owned(),
+                chosen: default_locale.to_owned(),
+                reason: NegotiationReason::Default,
+            },
+            strict_negotiation: false,
         }
     }
 }
@@ -167,6 +325,70 @@ impl Localization {
         Localization::default()
     }
 
+    /// Creates a Localization with the locale negotiated from an explicit, ordered
+    /// list of preferred locales, e.g. from a config. With no preferences this is
+    /// the same as [`Localization::new`]
+    pub fn with_preferences(prefs: &[LanguageIdentifier]) -> Self {
+        let mut localization = Localization::default();
+        if !prefs.is_empty() {
+            let report = localization.negotiate(prefs);
+            tracing::info!("negotiated locale: {report:?}");
+            localization.current_locale = report.chosen.clone();
+            localization.negotiation_report = report;
+        }
+        localization
+    }
+
+    /// Creates a Localization using the locale persisted by
+    /// [`Localization::set_locale_persisted`] in a previous session, if any. A
+    /// persisted locale which is no longer available is ignored and overwritten
+    pub fn from_data_path(path: &DataPath) -> Self {
+        let mut localization = Localization::default();
+        let directory = Directory::new(path.path(DataPathType::Setting));
+
+        let Ok(persisted) = directory.get_file(LOCALE_FILE.to_owned()) else {
+            return localization;
+        };
+
+        match persisted.trim().parse::<LanguageIdentifier>() {
+            Ok(locale) if localization.available_locales.contains(&locale) => {
+                localization.current_locale = locale;
+            }
+            _ => {
+                tracing::warn!(
+                    "persisted locale '{}' is not available, using {}",
+                    persisted.trim(),
+                    &localization.current_locale
+                );
+                if let Err(err) = localization.persist_locale(path) {
+                    tracing::error!("could not overwrite persisted locale: {err}");
+                }
+            }
+        }
+
+        localization
+    }
+
+    /// Sets the current locale and persists it so [`Localization::from_data_path`]
+    /// picks it up next session
+    pub fn set_locale_persisted(
+        &mut self,
+        locale: LanguageIdentifier,
+        path: &DataPath,
+    ) -> Result<(), IntlError> {
+        self.set_locale(locale)?;
+        self.persist_locale(path)
+    }
+
+    fn persist_locale(&self, path: &DataPath) -> Result<(), IntlError> {
+        write_file(
+            &path.path(DataPathType::Setting),
+            LOCALE_FILE.to_owned(),
+            &self.current_locale.to_string(),
+        )?;
+        Ok(())
+    }
+
     /// Disable bidirectional isolation markers. mostly useful for tests
     pub fn no_bidi() -> Self {
         Localization {
@@ -180,49 +402,218 @@ impl Localization {
         self.get_cached_string(id, None)
     }
 
-    /// Load a fluent bundle given a language identifier. Only looks in the static
-    /// ftl files baked into the binary
-    fn load_bundle(lang: &LanguageIdentifier) -> Result<Bundle, IntlError> {
-        for ftl in &FTLS {
-            if &ftl.identifier == lang {
-                let mut bundle = FluentBundle::new(vec![lang.to_owned()]);
-                let resource = FluentResource::try_new(ftl.ftl.to_string());
-                match resource {
-                    Err((resource, errors)) => {
-                        for error in errors {
-                            tracing::error!("load_bundle ({lang}): {error}");
-                        }
-
-                        tracing::warn!("load_bundle ({}: loading bundle with errors", lang);
-                        if let Err(errs) = bundle.add_resource(resource) {
-                            for err in errs {
-                                tracing::error!("adding resource: {err}");
-                            }
-                        }
-                    }
+    /// Creates a Localization that also loads `<locale>/main.ftl` files from `path`.
+    /// Locales found on disk take precedence over the ftl files baked into the binary,
+    /// which are still used for every locale that isn't on disk
+    pub fn from_dir(path: &Path) -> Result<Self, IntlError> {
+        let mut localization = Localization::default();
+
+        for entry in fs::read_dir(path)? {
+            let entry = entry?;
+            let ftl_path = entry.path().join("main.ftl");
+            if !ftl_path.is_file() {
+                continue;
+            }
 
-                    Ok(resource) => {
-                        tracing::info!("loaded {} bundle OK!", lang);
-                        if let Err(errs) = bundle.add_resource(resource) {
-                            for err in errs {
-                                tracing::error!("adding resource 2: {err}");
-                            }
-                        }
-                    }
-                }
+            let Some(lang) = entry
+                .file_name()
+                .to_str()
+                .and_then(|name| name.parse::<LanguageIdentifier>().ok())
+            else {
+                tracing::warn!(
+                    "from_dir: {:?} is not a locale, skipping",
+                    entry.file_name()
+                );
+                continue;
+            };
 
-                return Ok(bundle);
+            let ftl = fs::read_to_string(&ftl_path)?;
+            let built = localization.build_bundle(&lang, ftl.clone());
+            if !built.errors.is_empty() {
+                return Err(IntlError::ParseErrors(lang, built.errors));
             }
+
+            localization.add_runtime_ftl(lang.clone(), ftl);
+            localization.insert_bundle(&lang, built);
         }
 
-        // no static ftl for this LanguageIdentifier
-        Err(IntlError::NoFtl(lang.to_owned()))
+        Ok(localization)
     }
 
-    fn get_bundle<'a>(&'a self, lang: &LanguageIdentifier) -> &'a Bundle {
-        self.bundles
+    /// Creates a Localization with only the given in-memory ftl and none of the
+    /// translations baked into the binary, e.g. for tests that need their own
+    /// messages. en-US is the current and fallback locale if given, otherwise the
+    /// first locale in sorted order is
+    pub fn from_ftl_map(mut map: HashMap<LanguageIdentifier, String>) -> Result<Self, IntlError> {
+        let mut locales: Vec<LanguageIdentifier> = map.keys().cloned().collect();
+        locales.sort_by_key(|locale| locale.to_string());
+        let default_locale = if map.contains_key(&EN_US) {
+            EN_US
+        } else {
+            locales.first().cloned().ok_or(IntlError::NoFtl(EN_US))?
+        };
+
+        let mut localization = Localization {
+            current_locale: default_locale.clone(),
+            available_locales: Vec::new(),
+            fallback_locale: default_locale.clone(),
+            fallback_chain: HashMap::new(),
+            locale_native_names: HashMap::new(),
+            ..Localization::default()
+        };
+        localization.negotiation_report.fallback = default_locale.clone();
+        localization.negotiation_report.chosen = default_locale;
+
+        for locale in locales {
+            let ftl = map.remove(&locale).unwrap_or_default();
+            localization.register_locale(locale.clone(), locale.to_string(), ftl)?;
+        }
+
+        Ok(localization)
+    }
+
+    /// Make a locale that isn't baked into the binary available, e.g. a community
+    /// translation into Italian, so it can be passed to [`Localization::set_locale`].
+    /// Registering a locale that is already available replaces its ftl. Nothing is
+    /// registered if the ftl has errors
+    pub fn register_locale(
+        &mut self,
+        id: LanguageIdentifier,
+        native_name: String,
+        ftl: String,
+    ) -> Result<(), IntlError> {
+        let built = self.build_bundle(&id, ftl.clone());
+        if !built.errors.is_empty() {
+            return Err(IntlError::ParseErrors(id, built.errors));
+        }
+
+        self.add_runtime_ftl(id.clone(), ftl);
+        self.locale_native_names.insert(id.clone(), native_name);
+        self.insert_bundle(&id, built);
+
+        Ok(())
+    }
+
+    /// Add ftl source for a locale that is loaded instead of the baked in one,
+    /// making the locale available if it wasn't already
+    fn add_runtime_ftl(&mut self, lang: LanguageIdentifier, ftl: String) {
+        if !self.available_locales.contains(&lang) {
+            self.available_locales.push(lang.clone());
+        }
+
+        self.locale_native_names
+            .entry(lang.clone())
+            .or_insert_with(|| lang.to_string());
+
+        self.string_cache.remove(&lang);
+        self.bundles.remove(&lang);
+        self.runtime_ftls.insert(lang, ftl);
+    }
+
+    /// The ftl source for a locale, preferring ftl loaded at runtime over the static
+    /// ftl files baked into the binary
+    fn ftl_source(&self, lang: &LanguageIdentifier) -> Option<&str> {
+        if let Some(ftl) = self.runtime_ftls.get(lang) {
+            return Some(ftl);
+        }
+
+        FTLS.iter()
+            .find(|ftl| &ftl.identifier == lang)
+            .map(|ftl| ftl.ftl)
+    }
+
+    /// Load a fluent bundle given a language identifier, along with any errors
+    /// parsing its ftl
+    fn load_bundle(&self, lang: &LanguageIdentifier) -> Result<BuiltBundle, IntlError> {
+        let Some(ftl) = self.ftl_source(lang) else {
+            // no ftl for this LanguageIdentifier
+            return Err(IntlError::NoFtl(lang.to_owned()));
+        };
+
+        Ok(self.build_bundle(lang, ftl.to_string()))
+    }
+
+    /// Build a bundle from ftl source. Parse errors are logged and returned along
+    /// with the bundle, which contains every message that could be parsed
+    fn build_bundle(&self, lang: &LanguageIdentifier, ftl: String) -> BuiltBundle {
+        let (resource, errors) = parse_ftl(lang, ftl);
+        self.bundle_from_resource(lang, resource, errors)
+    }
+
+    /// Build a bundle from parsed ftl, appending any errors adding it to `errors`
+    fn bundle_from_resource(
+        &self,
+        lang: &LanguageIdentifier,
+        resource: FluentResource,
+        mut errors: Vec<String>,
+    ) -> BuiltBundle {
+        let mut bundle = FluentBundle::new_concurrent(vec![lang.to_owned()]);
+
+        bundle.set_formatter(Some(format::format_value));
+        // registered first so they can override the builtins
+        for (name, function) in &self.functions {
+            let function = function.clone();
+            if let Err(err) =
+                bundle.add_function(name, move |positional, named| function(positional, named))
+            {
+                tracing::error!("adding function {name}: {err}");
+            }
+        }
+        let _ = bundle.add_builtins();
+        let date_style = self
+            .format_overrides
             .get(lang)
-            .expect("make sure to call ensure_bundle!")
+            .and_then(|options| options.date_style.clone());
+        let _ = bundle.add_function(
+            "DATETIME",
+            format::datetime_function(lang.to_owned(), date_style),
+        );
+
+        let mut duplicate_ids = Vec::new();
+        if let Err(errs) = bundle.add_resource(resource) {
+            for err in errs {
+                tracing::error!("adding resource: {err}");
+                errors.push(err.to_string());
+
+                // fluent keeps the first definition of an id and reports the rest
+                if let FluentError::Overriding { id, .. } = err {
+                    duplicate_ids.push(id);
+                }
+            }
+        }
+
+        // overrides were checked for errors when they were added
+        for ftl in self.override_ftls.get(lang).into_iter().flatten() {
+            let (resource, _) = parse_ftl(lang, ftl.to_owned());
+            bundle.add_resource_overriding(resource);
+        }
+
+        BuiltBundle {
+            bundle,
+            errors,
+            duplicate_ids,
+        }
+    }
+
+    /// Layer a small ftl over a locale's bundle, shadowing the messages it defines
+    /// while leaving every other message intact, e.g. to tweak a few strings for a
+    /// deployment. Later overrides shadow earlier ones. Overrides last until
+    /// [`Localization::clear_cache`]
+    pub fn add_override(&mut self, locale: LanguageIdentifier, ftl: &str) -> Result<(), IntlError> {
+        let (_, errors) = parse_ftl(&locale, ftl.to_owned());
+        if !errors.is_empty() {
+            return Err(IntlError::ParseErrors(locale, errors));
+        }
+
+        // rebuilt with the override on next use
+        self.bundles.remove(&locale);
+        self.string_cache.remove(&locale);
+        self.override_ftls
+            .entry(locale)
+            .or_default()
+            .push(ftl.to_owned());
+
+        Ok(())
     }
 
     fn has_bundle(&self, lang: &LanguageIdentifier) -> bool {
@@ -230,30 +621,286 @@ impl Localization {
     }
 
     fn try_load_bundle(&mut self, lang: &LanguageIdentifier) -> Result<(), IntlError> {
-        let mut bundle = Self::load_bundle(lang)?;
-        if !self.use_isolating {
+        let built = self.load_bundle(lang)?;
+        self.insert_bundle(lang, built);
+        Ok(())
+    }
+
+    fn insert_bundle(&mut self, lang: &LanguageIdentifier, built: BuiltBundle) {
+        let BuiltBundle {
+            mut bundle,
+            errors,
+            duplicate_ids,
+        } = built;
+
+        if errors.is_empty() {
+            self.bundle_errors.remove(lang);
+        } else {
+            self.bundle_errors.insert(lang.to_owned(), errors);
+        }
+
+        if duplicate_ids.is_empty() {
+            self.duplicate_ids.remove(lang);
+        } else {
+            self.duplicate_ids.insert(lang.to_owned(), duplicate_ids);
+        }
+
+        if !self.bundle_isolating(lang) {
             bundle.set_use_isolating(false);
         }
         self.bundles.insert(lang.to_owned(), bundle);
-        Ok(())
+
+        if let Some(observer) = &mut self.bundle_loaded_observer {
+            observer(lang);
+        }
+    }
+
+    /// Whether a locale's bundle isolates placeables by default. Right-to-left
+    /// text garbles left-to-right placeables without isolation, so disabling it is
+    /// only honored for left-to-right locales
+    fn bundle_isolating(&self, lang: &LanguageIdentifier) -> bool {
+        self.use_isolating || is_locale_rtl(lang)
+    }
+
+    /// Parse the ftl of every available locale that isn't loaded yet on a
+    /// background thread, without blocking or borrowing `self` while parsing.
+    /// Hand what the receiver yields to [`Localization::insert_parsed`]
+    pub fn preload_all_async(&self) -> Receiver<Vec<ParsedFtl>> {
+        let sources: Vec<(LanguageIdentifier, String)> = self
+            .available_locales
+            .iter()
+            .filter(|locale| !self.has_bundle(locale))
+            .filter_map(|locale| Some((locale.clone(), self.ftl_source(locale)?.to_owned())))
+            .collect();
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            let parsed = sources
+                .into_iter()
+                .map(|(locale, ftl)| {
+                    let (resource, errors) = parse_ftl(&locale, ftl);
+                    ParsedFtl {
+                        locale,
+                        resource,
+                        errors,
+                    }
+                })
+                .collect();
+
+            // the receiver may have been dropped, nothing to do then
+            let _ = sender.send(parsed);
+        });
+
+        receiver
+    }
+
+    /// Load bundles from ftl parsed by [`Localization::preload_all_async`].
+    /// Locales that were loaded in the meantime are skipped
+    pub fn insert_parsed(&mut self, parsed: Vec<ParsedFtl>) {
+        for ParsedFtl {
+            locale,
+            resource,
+            errors,
+        } in parsed
+        {
+            if self.has_bundle(&locale) {
+                continue;
+            }
+
+            let built = self.bundle_from_resource(&locale, resource, errors);
+            self.insert_bundle(&locale, built);
+        }
+    }
+
+    /// The errors parsing the ftl of a loaded bundle. Bundles still load with every
+    /// message that could be parsed, so strict callers should check this once the
+    /// bundle is loaded
+    pub fn bundle_errors(&self, lang: &LanguageIdentifier) -> &[String] {
+        self.bundle_errors
+            .get(lang)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The message and term ids defined more than once in the ftl of a loaded
+    /// bundle, e.g. from merging translation contributions. Only the first
+    /// definition of each is used
+    pub fn duplicate_ids(&self, lang: &LanguageIdentifier) -> &[String] {
+        self.duplicate_ids
+            .get(lang)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Parses the ftl of every available locale and formats each message and
+    /// attribute, filling variables with a placeholder number. Every failure is
+    /// collected rather than stopping at the first, so CI gets a full report
+    pub fn validate_all(&self) -> Result<(), Vec<(LanguageIdentifier, IntlError)>> {
+        let mut failures = vec![];
+
+        for locale in &self.available_locales {
+            let Some(ftl) = self.ftl_source(locale) else {
+                failures.push((locale.clone(), IntlError::NoFtl(locale.clone())));
+                continue;
+            };
+
+            let BuiltBundle { bundle, errors, .. } = self.build_bundle(locale, ftl.to_string());
+            if !errors.is_empty() {
+                failures.push((
+                    locale.clone(),
+                    IntlError::ParseErrors(locale.clone(), errors),
+                ));
+            }
+
+            for id in message_ids(ftl) {
+                let Some(message) = bundle.get_message(&id) else {
+                    continue;
+                };
+
+                let patterns = message
+                    .value()
+                    .into_iter()
+                    .chain(message.attributes().map(|attr| attr.value()));
+
+                let mut errors = vec![];
+                for pattern in patterns {
+                    let mut variables = BTreeSet::new();
+                    collect_pattern_variables(pattern, &mut variables);
+
+                    let mut args = FluentArgs::new();
+                    for variable in variables {
+                        args.set(variable, 1);
+                    }
+
+                    bundle.format_pattern(pattern, Some(&args), &mut errors);
+                }
+
+                if !errors.is_empty() {
+                    let errors = errors.iter().map(|err| err.to_string()).collect();
+                    failures.push((
+                        locale.clone(),
+                        IntlError::FormatErrors(IntlKeyBuf::new(id), errors),
+                    ));
+                }
+            }
+        }
+
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+
+    /// Make a function callable from ftl in every locale, e.g. `{ SHORTEN($url) }`.
+    /// Functions named `NUMBER` or `DATETIME` replace the builtin ones
+    pub fn add_function(
+        &mut self,
+        name: &str,
+        function: impl for<'a> Fn(&[FluentValue<'a>], &FluentArgs) -> FluentValue<'a>
+            + Send
+            + Sync
+            + 'static,
+    ) {
+        self.functions.retain(|(existing, _)| existing != name);
+        self.functions.push((name.to_owned(), Arc::new(function)));
+
+        // reload bundles so they pick up the function
+        self.bundles.clear();
+        self.string_cache.clear();
+    }
+
+    /// Register a callback that fires each time a bundle finishes loading, e.g.
+    /// to show translation loading progress on a splash screen
+    pub fn on_bundle_loaded(&mut self, observer: BundleLoadedObserver) {
+        self.bundle_loaded_observer = Some(observer);
+    }
+
+    /// Set how strings without a translation are displayed, e.g. wrapping them in
+    /// ⟦...⟧ so missing translations stand out in screenshots
+    pub fn set_missing_placeholder(
+        &mut self,
+        fmt: impl Fn(&str) -> String + Send + Sync + 'static,
+    ) {
+        self.missing_placeholder = Some(Box::new(fmt));
+    }
+
+    /// Format the fallback text of a string that could not be translated
+    pub fn format_missing(&self, fallback: &str) -> String {
+        match &self.missing_placeholder {
+            Some(fmt) => fmt(fallback),
+            None => fallback.to_owned(),
+        }
     }
 
     pub fn normalized_ftl_key(&mut self, key: &str, comment: &str) -> IntlKeyBuf {
-        match self.get_ftl_key(key) {
+        match self.get_ftl_key(key, comment) {
             Some(intl_key) => intl_key,
             None => {
                 self.insert_ftl_key(key, comment);
-                self.get_ftl_key(key).unwrap()
+                self.get_ftl_key(key, comment).unwrap()
             }
         }
     }
 
-    fn get_ftl_key(&self, cache_key: &str) -> Option<IntlKeyBuf> {
-        self.normalized_key_cache.get(cache_key).cloned()
+    /// Export the (source string, comment) to normalized key mapping built up so far.
+    /// Useful for checking that `fixup_key`/`simple_hash` agree with the python
+    /// extraction script
+    pub fn export_key_map(&self) -> BTreeMap<(String, String), String> {
+        self.normalized_keys()
+            .map(|(source, comment, key)| {
+                ((source.to_owned(), comment.to_owned()), key.to_string())
+            })
+            .collect()
+    }
+
+    /// Every normalized key so far, along with the source string and comment it
+    /// was made from
+    fn normalized_keys(&self) -> impl Iterator<Item = (&str, &str, &IntlKeyBuf)> {
+        self.normalized_key_cache
+            .iter()
+            .flat_map(|(source, by_comment)| {
+                by_comment
+                    .iter()
+                    .map(move |(comment, key)| (source.as_str(), comment.as_str(), key))
+            })
+    }
+
+    /// Normalized keys which more than one distinct source string was normalized
+    /// to so far, along with those source strings, sorted. Both strings would
+    /// share one ftl entry, which usually means an extraction bug
+    pub fn audit_key_collisions(&self) -> Vec<(String, Vec<String>)> {
+        let mut sources_by_key: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for (source, _, key) in self.normalized_keys() {
+            sources_by_key
+                .entry(key.to_string())
+                .or_default()
+                .push(source.to_owned());
+        }
+
+        sources_by_key
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(|(key, mut sources)| {
+                sources.sort();
+                (key, sources)
+            })
+            .collect()
+    }
+
+    fn get_ftl_key(&self, cache_key: &str, comment: &str) -> Option<IntlKeyBuf> {
+        self.normalized_key_cache
+            .get(cache_key)
+            .and_then(|by_comment| by_comment.get(comment))
+            .cloned()
     }
 
     fn insert_ftl_key(&mut self, cache_key: &str, comment: &str) {
-        let mut result = fixup_key(cache_key);
+        let mut result = if self.transliterate_keys {
+            fixup_key_transliterated(cache_key)
+        } else {
+            fixup_key(cache_key)
+        };
 
         // Ensure the key starts with a letter (Fluent requirement)
         if result.is_empty() || !result.chars().next().unwrap().is_ascii_alphabetic() {
@@ -261,9 +908,12 @@ impl Localization {
         }
 
         // If we have a comment, append a hash of it to reduce collisions
-        let hash_str = format!("_{}", simple_hash(comment));
+        let hash_str = format!("_{}", simple_hash(comment, self.key_hash_bytes));
         result.push_str(&hash_str);
 
+        #[cfg(debug_assertions)]
+        self.check_key_collision(&result, cache_key, comment);
+
         tracing::debug!(
             "normalize_ftl_key: original='{}', final='{}'",
             cache_key,
@@ -271,10 +921,57 @@ impl Localization {
         );
 
         self.normalized_key_cache
-            .insert(cache_key.to_owned(), IntlKeyBuf::new(result));
+            .entry(cache_key.to_owned())
+            .or_default()
+            .insert(comment.to_owned(), IntlKeyBuf::new(result));
+    }
+
+    /// Set how many bytes of the comment hash are appended to normalized keys.
+    /// The ftl catalog is generated with [`KEY_HASH_BYTES`], so this is only
+    /// useful for tooling that generates its own
+    pub fn set_key_hash_bytes(&mut self, bytes: usize) {
+        self.key_hash_bytes = bytes.clamp(1, 16);
+        self.normalized_key_cache.clear();
+        #[cfg(debug_assertions)]
+        self.normalized_key_sources.clear();
+    }
+
+    /// Normalize keys with [`fixup_key_transliterated`] instead of [`fixup_key`],
+    /// so strings like "Café" keep their letters. The python extraction script
+    /// only produces [`fixup_key`] keys, so this is off by default
+    pub fn set_transliterate_keys(&mut self, transliterate: bool) {
+        self.transliterate_keys = transliterate;
+        self.normalized_key_cache.clear();
+        #[cfg(debug_assertions)]
+        self.normalized_key_sources.clear();
+    }
+
+    /// Warn when a different string or comment was already normalized to `key`,
+    /// since both would silently share one ftl entry
+    #[cfg(debug_assertions)]
+    fn check_key_collision(&mut self, key: &str, source: &str, comment: &str) {
+        match self.normalized_key_sources.get(key) {
+            Some((other_source, other_comment))
+                if other_source != source || other_comment != comment =>
+            {
+                tracing::warn!(
+                    "normalized key collision: '{}' ({}) and '{}' ({}) both map to {}",
+                    other_source,
+                    other_comment,
+                    source,
+                    comment,
+                    key
+                );
+            }
+            Some(_) => {}
+            None => {
+                self.normalized_key_sources
+                    .insert(key.to_owned(), (source.to_owned(), comment.to_owned()));
+            }
+        }
     }
 
-    fn get_cached_string_no_args<'key>(
+    fn lookup_cached_string<'key>(
         &'key self,
         lang: &LanguageIdentifier,
         id: IntlKey<'key>,
@@ -290,6 +987,7 @@ impl Localization {
                 );
                 */
 
+                self.cache_hits.fetch_add(1, Ordering::Relaxed);
                 return Ok(Cow::Borrowed(cached_string));
             }
         }
@@ -297,35 +995,67 @@ impl Localization {
         Err(IntlError::NotFound(id.to_owned()))
     }
 
-    fn ensure_bundle(&mut self) -> Result<(), IntlError> {
+    pub(super) fn ensure_bundle(&mut self) -> Result<(), IntlError> {
         let locale = self.current_locale.clone();
         if !self.has_bundle(&locale) {
-            match self.try_load_bundle(&locale) {
-                Err(err) => {
-                    tracing::warn!(
-                        "tried to load bundle {} but failed with '{err}'. using fallback {}",
-                        &locale,
-                        &self.fallback_locale
-                    );
-                    self.try_load_bundle(&locale)
-                        .expect("failed to load fallback bundle!?");
-
-                    Ok(())
-                }
+            if let Err(err) = self.try_load_bundle(&locale) {
+                tracing::warn!(
+                    "tried to load bundle {} but failed with '{err}'. using fallback {}",
+                    &locale,
+                    &self.fallback_locale
+                );
 
-                Ok(()) => Ok(()),
+                let fallback = self.fallback_locale.clone();
+                if !self.has_bundle(&fallback) {
+                    if let Err(err) = self.try_load_bundle(&fallback) {
+                        tracing::error!("failed to load fallback bundle {fallback}: {err}");
+                        return Err(err);
+                    }
+                }
             }
-        } else {
-            Ok(())
         }
+
+        self.ensure_fallback_chain_bundles();
+        Ok(())
     }
 
-    fn get_current_bundle(&self) -> &Bundle {
-        if self.has_bundle(&self.current_locale) {
-            return self.get_bundle(&self.current_locale);
+    /// Load the bundles in the current locale's fallback chain
+    fn ensure_fallback_chain_bundles(&mut self) {
+        for lang in self.get_fallback_chain(&self.current_locale).to_vec() {
+            if !self.has_bundle(&lang) {
+                if let Err(err) = self.try_load_bundle(&lang) {
+                    tracing::warn!("could not load fallback bundle {lang}: {err}");
+                }
+            }
         }
+    }
 
-        self.get_bundle(&self.fallback_locale)
+    /// Gets the first loaded bundle containing `id`, trying the current locale and
+    /// then its fallback chain in order
+    fn get_resolving_bundle(&self, id: IntlKey<'_>) -> Option<(&LanguageIdentifier, &Bundle)> {
+        std::iter::once(&self.current_locale)
+            .chain(self.get_fallback_chain(&self.current_locale))
+            .filter_map(|lang| Some((lang, self.bundles.get(lang)?)))
+            .find(|(_, bundle)| bundle.has_message(id.as_str()))
+    }
+
+    /// Gets the locales tried in order when a message is missing from `locale`
+    pub fn get_fallback_chain(&self, locale: &LanguageIdentifier) -> &[LanguageIdentifier] {
+        self.fallback_chain
+            .get(locale)
+            .map(Vec::as_slice)
+            .unwrap_or(std::slice::from_ref(&self.fallback_locale))
+    }
+
+    /// Sets the locales tried in order when a message is missing from `locale`
+    pub fn set_fallback_chain(
+        &mut self,
+        locale: LanguageIdentifier,
+        chain: Vec<LanguageIdentifier>,
+    ) {
+        // cached strings may have been resolved through the old chain
+        self.string_cache.remove(&locale);
+        self.fallback_chain.insert(locale, chain);
     }
 
     /// Gets cached string result, or formats it and caches the result
@@ -334,44 +1064,101 @@ impl Localization {
         id: IntlKey<'_>,
         args: Option<&FluentArgs>,
     ) -> Result<String, IntlError> {
+        self.track_supplied_variables(id, args);
         self.ensure_bundle()?;
+        self.get_cached_string_loaded(id, args)
+    }
 
-        if args.is_none() {
-            if let Ok(result) = self.get_cached_string_no_args(&self.current_locale, id) {
-                return Ok(result.to_string());
-            }
+    /// Whether the current locale has its own translation of `id`, rather than
+    /// falling back to another locale. Nothing is formatted
+    pub fn is_translated(&mut self, id: IntlKey<'_>) -> bool {
+        if let Err(err) = self.ensure_bundle() {
+            tracing::error!("is_translated: could not load bundles: {err}");
+            return false;
         }
 
-        let result = {
-            let bundle = self.get_current_bundle();
+        self.bundles
+            .get(&self.current_locale)
+            .is_some_and(|bundle| bundle.has_message(id.as_str()))
+    }
 
-            let message = bundle
-                .get_message(id.as_str())
-                .ok_or_else(|| IntlError::NotFound(id.to_owned()))?;
+    /// Formats a message with its `$count` argument set, e.g. to check that each
+    /// plural form of a message renders as expected
+    pub fn format_with_count(&mut self, id: IntlKey<'_>, count: i64) -> Result<String, IntlError> {
+        let mut args = FluentArgs::new();
+        args.set("count", count);
+        self.get_cached_string(id, Some(&args))
+    }
 
-            let pattern = message
-                .value()
-                .ok_or_else(|| IntlError::NoValue(id.to_owned()))?;
+    /// Gets several localized strings at once, loading bundles only once. Each
+    /// key gets its own result so one missing key doesn't fail the whole batch
+    pub fn get_strings(&mut self, ids: &[IntlKey<'_>]) -> Vec<Result<String, IntlError>> {
+        if let Err(err) = self.ensure_bundle() {
+            tracing::error!("get_strings: {err}");
+            return ids
+                .iter()
+                .map(|_| Err(IntlError::NoBundle(self.current_locale.clone())))
+                .collect();
+        }
 
-            let mut errors = Vec::with_capacity(0);
-            let result = bundle.format_pattern(pattern, args, &mut errors);
+        ids.iter()
+            .map(|id| {
+                self.track_supplied_variables(*id, None);
+                self.get_cached_string_loaded(*id, None)
+            })
+            .collect()
+    }
 
-            if !errors.is_empty() {
-                tracing::warn!("Localization errors for {}: {:?}", id, &errors);
+    /// The cached result of a lookup in the current locale, if there is one
+    pub(super) fn cached_string(
+        &self,
+        id: IntlKey<'_>,
+        args: Option<&FluentArgs>,
+    ) -> Option<String> {
+        let cache_key = self.string_cache_key(id, args)?;
+        self.lookup_cached_string(&self.current_locale, IntlKey::new(&cache_key))
+            .ok()
+            .map(Cow::into_owned)
+    }
+
+    /// [`Localization::get_cached_string`] once the bundles are loaded
+    fn get_cached_string_loaded(
+        &mut self,
+        id: IntlKey<'_>,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, IntlError> {
+        let cache_key = self.string_cache_key(id, args);
+        if let Some(cache_key) = &cache_key {
+            let cached = self.lookup_cached_string(&self.current_locale, IntlKey::new(cache_key));
+            if let Ok(result) = cached {
+                return Ok(result.to_string());
             }
+        }
 
-            result.to_string()
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        let (mut result, _) = match self.format_resolved(id, args) {
+            Ok(resolved) => resolved,
+            Err(err) => {
+                if let IntlError::NotFound(_) = err {
+                    self.warn_missing_key(id);
+                }
+                return Err(err);
+            }
         };
+        if self.current_locale == EN_XA {
+            result = expand_pseudolocalized(&result, self.pseudolocale_expansion);
+        }
 
-        // Only cache simple strings without arguments
-        // This prevents caching issues when the same message ID is used with different arguments
-        if args.is_none() {
-            self.cache_string(self.current_locale.clone(), id, result.as_str());
+        if let Some(cache_key) = cache_key {
             tracing::debug!(
                 "Cached string result for '{}' in locale: {}",
                 id,
                 &self.current_locale
             );
+            self.string_cache
+                .entry(self.current_locale.clone())
+                .or_default()
+                .insert(cache_key, result.clone());
         } else {
             tracing::trace!("Not caching string '{}' due to arguments", id);
         }
@@ -379,127 +1166,2613 @@ impl Localization {
         Ok(result)
     }
 
-    pub fn cache_string<'a>(&mut self, locale: LanguageIdentifier, id: IntlKey<'a>, result: &str) {
-        tracing::debug!("Cached string result for '{}' in locale: {}", id, &locale);
-        let locale_cache = self.string_cache.entry(locale).or_default();
-        locale_cache.insert(id.to_owned().to_string(), result.to_owned());
+    /// Warns about a missing key, only the first time it's missing in the current
+    /// locale so lookups every frame don't flood the logs
+    fn warn_missing_key(&mut self, id: IntlKey<'_>) {
+        let warned = self
+            .warned_missing_keys
+            .entry(self.current_locale.clone())
+            .or_default();
+
+        if !warned.contains(id.as_str()) {
+            tracing::warn!("message not found in {}: {}", self.current_locale, id);
+            warned.insert(id.as_str().to_owned());
+        }
     }
 
-    /// Sets the current locale
-    pub fn set_locale(&mut self, locale: LanguageIdentifier) -> Result<(), IntlError> {
-        tracing::info!("Attempting to set locale to: {}", locale);
-        tracing::info!("Available locales: {:?}", self.available_locales);
+    /// Forget which missing keys were warned about so they're reported again, e.g.
+    /// after [`Localization::clear_cache`] during a hot reload
+    pub fn reset_missing_key_warnings(&mut self) {
+        self.warned_missing_keys.clear();
+    }
 
-        // Validate that the locale is available
-        if !self.available_locales.contains(&locale) {
-            tracing::error!(
-                "Locale {} is not available. Available locales: {:?}",
-                locale,
-                self.available_locales
-            );
-            return Err(IntlError::LocaleNotAvailable(locale));
+    /// Gets a localized string, or a readable form of the key itself if it can't be
+    /// formatted, e.g. "Add account" for a missing `Add_account_1cfc87`
+    pub fn get_string_or_key(&mut self, id: IntlKey<'_>) -> String {
+        self.get_cached_string(id, None)
+            .unwrap_or_else(|_| humanize_key(id.as_str(), self.key_hash_bytes))
+    }
+
+    /// Gets a localized string with bidi isolation of placeables turned on or off
+    /// for this call only, e.g. off for a string embedded in a log line
+    pub fn get_string_isolated(
+        &mut self,
+        id: IntlKey<'_>,
+        isolating: bool,
+    ) -> Result<String, IntlError> {
+        self.track_supplied_variables(id, None);
+        self.ensure_bundle()?;
+
+        // isolated and non-isolated results differ, so they're cached separately
+        let cache_key = format!("{}\0isolating:{}", id.as_str(), isolating);
+        let cached = self.lookup_cached_string(&self.current_locale, IntlKey::new(&cache_key));
+        if let Ok(result) = cached {
+            return Ok(result.into_owned());
         }
 
-        tracing::info!(
-            "Switching locale from {} to {}",
-            &self.current_locale,
-            &locale
+        let lang = self
+            .get_resolving_bundle(id)
+            .map(|(lang, _)| lang.clone())
+            .ok_or_else(|| IntlError::NotFound(id.to_owned()))?;
+        let default_isolating = self.bundle_isolating(&lang);
+
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+        if let Some(bundle) = self.bundles.get_mut(&lang) {
+            bundle.set_use_isolating(isolating);
+        }
+        let formatted = self.format_resolved(id, None);
+        if let Some(bundle) = self.bundles.get_mut(&lang) {
+            bundle.set_use_isolating(default_isolating);
+        }
+
+        let (mut result, _) = formatted?;
+        if self.current_locale == EN_XA {
+            result = expand_pseudolocalized(&result, self.pseudolocale_expansion);
+        }
+
+        self.string_cache
+            .entry(self.current_locale.clone())
+            .or_default()
+            .insert(cache_key, result.clone());
+
+        Ok(result)
+    }
+
+    /// Gets a localized string along with the locale of the bundle that produced
+    /// it, which differs from the current locale when the message fell back, e.g.
+    /// to mark partially translated text
+    pub fn get_string_resolved(
+        &mut self,
+        id: IntlKey<'_>,
+    ) -> Result<(String, LanguageIdentifier), IntlError> {
+        self.track_supplied_variables(id, None);
+        self.ensure_bundle()?;
+        self.format_resolved(id, None)
+    }
+
+    /// Formats a message with the first bundle in the fallback chain that has it
+    fn format_resolved(
+        &self,
+        id: IntlKey<'_>,
+        args: Option<&FluentArgs>,
+    ) -> Result<(String, LanguageIdentifier), IntlError> {
+        let (lang, bundle) = self
+            .get_resolving_bundle(id)
+            .ok_or_else(|| IntlError::NotFound(id.to_owned()))?;
+
+        let message = bundle
+            .get_message(id.as_str())
+            .ok_or_else(|| IntlError::NotFound(id.to_owned()))?;
+
+        let pattern = message
+            .value()
+            .ok_or_else(|| IntlError::NoValue(id.to_owned()))?;
+
+        let mut errors = Vec::with_capacity(0);
+        let result = bundle.format_pattern(pattern, args, &mut errors);
+
+        if !errors.is_empty() {
+            tracing::warn!("Localization errors for {}: {:?}", id, &errors);
+        }
+
+        Ok((result.to_string(), lang.clone()))
+    }
+
+    /// Gets a localized attribute of a message, e.g. the `.aria-label` of
+    ///
+    /// ```ftl
+    /// search = Search
+    ///     .aria-label = Search notes
+    /// ```
+    pub fn get_attribute(
+        &mut self,
+        id: IntlKey<'_>,
+        attr: &str,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, IntlError> {
+        self.track_supplied_variables(id, args);
+        self.ensure_bundle()?;
+
+        let (_, bundle) = self
+            .get_resolving_bundle(id)
+            .ok_or_else(|| IntlError::NotFound(id.to_owned()))?;
+
+        let attribute = bundle
+            .get_message(id.as_str())
+            .ok_or_else(|| IntlError::NotFound(id.to_owned()))?
+            .get_attribute(attr)
+            .ok_or_else(|| IntlError::NoAttribute(id.to_owned(), attr.to_owned()))?;
+
+        let mut errors = Vec::with_capacity(0);
+        let result = bundle.format_pattern(attribute.value(), args, &mut errors);
+
+        if !errors.is_empty() {
+            tracing::warn!("Localization errors for {}.{}: {:?}", id, attr, &errors);
+        }
+
+        Ok(result.to_string())
+    }
+
+    /// Pad strings in the en-XA pseudolocale to `factor` times their length, e.g.
+    /// 2.0 to stress layouts with text twice as long as english. Other locales are
+    /// never expanded
+    pub fn set_pseudolocale_expansion(&mut self, factor: f32) {
+        self.pseudolocale_expansion = factor.max(1.0);
+        // cached en-XA strings were expanded with the old factor
+        self.string_cache.remove(&EN_XA);
+    }
+
+    /// Cache strings formatted with arguments, keyed by the message and a hash of
+    /// the arguments. Off by default since every distinct set of arguments (e.g.
+    /// each timestamp) takes up a cache entry
+    pub fn set_cache_strings_with_args(&mut self, enabled: bool) {
+        self.cache_strings_with_args = enabled;
+    }
+
+    /// The string cache key for a message formatted with `args`, or None if the
+    /// result shouldn't be cached
+    fn string_cache_key(&self, id: IntlKey<'_>, args: Option<&FluentArgs>) -> Option<String> {
+        let Some(args) = args else {
+            return Some(id.as_str().to_owned());
+        };
+
+        if !self.cache_strings_with_args {
+            return None;
+        }
+
+        // a NUL can't appear in a message id, so these never collide with plain keys
+        Some(format!("{}\0{:016x}", id.as_str(), hash_args(args)?))
+    }
+
+    fn track_supplied_variables(&mut self, id: IntlKey<'_>, args: Option<&FluentArgs>) {
+        let supplied = match self.supplied_variables.get_mut(id.as_str()) {
+            Some(supplied) => supplied,
+            None => self
+                .supplied_variables
+                .entry(id.as_str().to_owned())
+                .or_default(),
+        };
+
+        for (name, _) in args.into_iter().flat_map(|args| args.iter()) {
+            if !supplied.contains(name) {
+                supplied.insert(name.to_owned());
+            }
+        }
+    }
+
+    /// Gets the names of the variables the message `id` references in the current
+    /// locale (or the fallback locale if the current one isn't loaded)
+    pub fn required_variables(&self, id: IntlKey<'_>) -> Option<Vec<String>> {
+        let bundle = self
+            .bundles
+            .get(&self.current_locale)
+            .or_else(|| self.bundles.get(&self.fallback_locale))?;
+        let pattern = bundle.get_message(id.as_str())?.value()?;
+
+        let mut variables = BTreeSet::new();
+        collect_pattern_variables(pattern, &mut variables);
+        Some(variables.into_iter().map(|v| v.to_owned()).collect())
+    }
+
+    /// Lists the messages looked up this session that reference variables which
+    /// were never supplied in any lookup, along with those variables. Such messages
+    /// will always render a placeholder.
+    ///
+    /// This is a heuristic: it only knows about messages that were actually looked
+    /// up during this run, so code paths that never executed are not covered
+    pub fn unsupplied_variables(&self) -> Vec<(String, Vec<String>)> {
+        let mut unsupplied: Vec<(String, Vec<String>)> = self
+            .supplied_variables
+            .iter()
+            .filter_map(|(key, supplied)| {
+                let missing: Vec<String> = self
+                    .required_variables(IntlKey::new(key))?
+                    .into_iter()
+                    .filter(|variable| !supplied.contains(variable))
+                    .collect();
+
+                if missing.is_empty() {
+                    None
+                } else {
+                    Some((key.to_owned(), missing))
+                }
+            })
+            .collect();
+        unsupplied.sort();
+
+        unsupplied
+    }
+
+    pub fn cache_string<'a>(&mut self, locale: LanguageIdentifier, id: IntlKey<'a>, result: &str) {
+        tracing::debug!("Cached string result for '{}' in locale: {}", id, &locale);
+        let locale_cache = self.string_cache.entry(locale).or_default();
+        locale_cache.insert(id.as_str().to_owned(), result.to_owned());
+    }
+
+    /// Sets the current locale
+    pub fn set_locale(&mut self, locale: LanguageIdentifier) -> Result<(), IntlError> {
+        tracing::info!("Attempting to set locale to: {}", locale);
+        tracing::info!("Available locales: {:?}", self.available_locales);
+
+        // Validate that the locale is available
+        if !self.available_locales.contains(&locale) {
+            tracing::error!(
+                "Locale {} is not available. Available locales: {:?}",
+                locale,
+                self.available_locales
+            );
+            return Err(IntlError::LocaleNotAvailable(locale));
+        }
+
+        tracing::info!(
+            "Switching locale from {} to {}",
+            &self.current_locale,
+            &locale
+        );
+        self.current_locale = locale;
+
+        // Clear caches when locale changes since they are locale-specific
+        self.string_cache.clear();
+        tracing::debug!("String cache cleared due to locale change");
+
+        Ok(())
+    }
+
+    /// Clears the parsed FluentResource cache (useful for development when FTL files change)
+    pub fn clear_cache(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.bundles.clear();
+        self.override_ftls.clear();
+        tracing::debug!("Parsed FluentResource cache cleared");
+
+        self.string_cache.clear();
+        self.cache_hits.store(0, Ordering::Relaxed);
+        self.cache_misses.store(0, Ordering::Relaxed);
+        tracing::debug!("String result cache cleared");
+
+        Ok(())
+    }
+
+    /// Gets the current locale
+    pub fn get_current_locale(&self) -> &LanguageIdentifier {
+        &self.current_locale
+    }
+
+    /// Gets all available locales
+    pub fn get_available_locales(&self) -> &[LanguageIdentifier] {
+        &self.available_locales
+    }
+
+    /// Gets the fallback locale
+    pub fn get_fallback_locale(&self) -> &LanguageIdentifier {
+        &self.fallback_locale
+    }
+
+    /// Sets the locale used for messages missing from the current locale and when
+    /// negotiation finds no preferred locale, e.g. German for a German-market build.
+    /// Fallback chains that ended in the old fallback locale end in this one instead
+    pub fn set_fallback_locale(&mut self, locale: LanguageIdentifier) -> Result<(), IntlError> {
+        if !self.available_locales.contains(&locale) {
+            return Err(IntlError::LocaleNotAvailable(locale));
+        }
+
+        let old = std::mem::replace(&mut self.fallback_locale, locale.clone());
+        for (lang, chain) in self.fallback_chain.iter_mut() {
+            for fallback in chain.iter_mut() {
+                if *fallback == old {
+                    *fallback = locale.clone();
+                }
+            }
+
+            let mut seen = HashSet::new();
+            chain.retain(|fallback| fallback != lang && seen.insert(fallback.clone()));
+        }
+
+        // cached strings may have been resolved through the old fallback
+        self.string_cache.clear();
+        Ok(())
+    }
+
+    pub fn get_locale_native_name(&self, locale: &LanguageIdentifier) -> Option<&str> {
+        self.locale_native_names.get(locale).map(|s| s.as_str())
+    }
+
+    /// The available locales paired with their native names, sorted by name for a
+    /// language picker the way [`Localization::compare`] sorts them. Locales without
+    /// a native name are listed by their tag. The selected entry is the one equal to
+    /// [`Localization::get_current_locale`]
+    pub fn locale_options(&self) -> Vec<(LanguageIdentifier, Cow<'_, str>)> {
+        let mut options: Vec<(LanguageIdentifier, Cow<'_, str>)> = self
+            .available_locales
+            .iter()
+            .map(|locale| {
+                let name = match self.get_locale_native_name(locale) {
+                    Some(name) => Cow::Borrowed(name),
+                    None => Cow::Owned(locale.to_string()),
+                };
+                (locale.clone(), name)
+            })
+            .collect();
+
+        options.sort_by(|(_, a_name), (_, b_name)| self.compare(a_name, b_name));
+
+        options
+    }
+
+    /// Gets cache statistics for monitoring performance
+    pub fn get_cache_stats(&self) -> Result<CacheStats, Box<dyn std::error::Error + Send + Sync>> {
+        let mut total_strings = 0;
+        for locale_cache in self.string_cache.values() {
+            total_strings += locale_cache.len();
+        }
+
+        Ok(CacheStats {
+            resource_cache_size: self.bundles.len(),
+            string_cache_size: total_strings,
+            cached_locales: self.bundles.keys().cloned().collect(),
+            hits: self.cache_hits.load(Ordering::Relaxed),
+            misses: self.cache_misses.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Whether the loaded bundle of `locale` contains the message `id`. Bundles are
+    /// loaded lazily, so this is false for a locale that hasn't been loaded yet
+    pub fn has_message(&self, locale: &LanguageIdentifier, id: &str) -> bool {
+        self.bundles
+            .get(locale)
+            .is_some_and(|bundle| bundle.has_message(id))
+    }
+
+    /// Limits the string cache size to prevent memory growth
+    pub fn limit_string_cache_size(
+        &mut self,
+        max_strings_per_locale: usize,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        for (locale, locale_cache) in self.string_cache.iter_mut() {
+            let evicted = locale_cache.evict_to(max_strings_per_locale);
+            if evicted > 0 {
+                tracing::debug!(
+                    "Evicted {} least recently used strings for locale {} due to size limit",
+                    evicted,
+                    locale
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formats a number with the grouping and decimal separators of the current
+    /// locale, e.g. 1234567.5 is "1,234,567.5" in en-US and "1.234.567,5" in de
+    pub fn format_number(&self, n: f64) -> String {
+        format::NumberSymbols::for_locale(&self.current_locale)
+            .with_options(self.format_overrides.get(&self.current_locale))
+            .format(&n.to_string(), true)
+    }
+
+    /// Formats a date, and the time if `with_time` is set, the way the current
+    /// locale writes them, e.g. "03/09/2025 2:05 PM" in en-US
+    pub fn format_datetime(&self, datetime: &chrono::NaiveDateTime, with_time: bool) -> String {
+        let date_style = self
+            .format_overrides
+            .get(&self.current_locale)
+            .and_then(|options| options.date_style.as_ref());
+        format::format_datetime(&self.current_locale, datetime, with_time, date_style)
+    }
+
+    /// Replace how `locale` formats numbers and dates in [`Localization::format_number`],
+    /// [`Localization::format_datetime`] and the ftl `DATETIME` function, e.g. to
+    /// write ISO dates regardless of locale. Fields left as `None` keep the
+    /// locale's defaults, and setting [`FormatOptions::default`] removes the override
+    pub fn set_format_override(&mut self, locale: LanguageIdentifier, options: FormatOptions) {
+        if options == FormatOptions::default() {
+            self.format_overrides.remove(&locale);
+        } else {
+            self.format_overrides.insert(locale.clone(), options);
+        }
+
+        // reload the bundle so DATETIME picks up the date style
+        self.bundles.remove(&locale);
+        self.string_cache.remove(&locale);
+    }
+
+    /// Compares two strings the way the current locale sorts them, e.g. for sorting
+    /// contact names. Unlike `str::cmp`, accented letters sort next to their base
+    /// letter, except where the locale treats them as letters of their own
+    pub fn compare(&self, a: &str, b: &str) -> std::cmp::Ordering {
+        collate::compare(&self.current_locale, a, b)
+    }
+
+    /// Sorts strings the way the current locale does, see [`Localization::compare`]
+    pub fn sort_strings(&self, strings: &mut [String]) {
+        strings.sort_by(|a, b| self.compare(a, b));
+    }
+
+    /// Formats a boolean as a localized "On"/"Off" label, for settings toggles
+    pub fn format_bool(&mut self, value: bool) -> String {
+        if value {
+            tr!(self, "On", "Label for a setting that is turned on")
+        } else {
+            tr!(self, "Off", "Label for a setting that is turned off")
+        }
+    }
+
+    /// Formats a boolean as a localized "Yes"/"No" answer
+    pub fn format_yes_no(&mut self, value: bool) -> String {
+        if value {
+            tr!(self, "Yes", "Affirmative answer for a yes/no value")
+        } else {
+            tr!(self, "No", "Negative answer for a yes/no value")
+        }
+    }
+
+    /// Export every message as CSV: one row per message id of the fallback locale and
+    /// one column per available locale. See [`Localization::write_export_csv`] to
+    /// stream the rows instead of building a string
+    pub fn export_all_csv(&mut self) -> String {
+        let keys = self.export_prepare();
+
+        let mut csv = self.export_csv_header();
+        for key in &keys {
+            csv.push_str(&self.export_csv_row(key));
+        }
+
+        csv
+    }
+
+    /// Same as [`Localization::export_all_csv`], but writes each row to `writer` as it
+    /// is formatted instead of holding the entire export in memory
+    pub fn write_export_csv(&mut self, mut writer: impl Write) -> io::Result<()> {
+        let keys = self.export_prepare();
+
+        writer.write_all(self.export_csv_header().as_bytes())?;
+        for key in &keys {
+            writer.write_all(self.export_csv_row(key).as_bytes())?;
+        }
+
+        writer.flush()
+    }
+
+    /// Load every available bundle and return the message ids to export
+    fn export_prepare(&mut self) -> Vec<String> {
+        self.preload_all();
+        self.ftl_source(&self.fallback_locale)
+            .map(message_ids)
+            .unwrap_or_default()
+    }
+
+    /// Load the bundles of all available locales that aren't loaded yet, so
+    /// switching locales later doesn't have to parse ftl
+    pub fn preload_all(&mut self) {
+        for locale in self.available_locales.clone() {
+            if !self.has_bundle(&locale) {
+                if let Err(err) = self.try_load_bundle(&locale) {
+                    tracing::warn!("could not load bundle {locale}: {err}");
+                }
+            }
+        }
+    }
+
+    /// Reports, for each available locale missing any of the `required` message ids,
+    /// which ones it is missing. Meant for CI checks that critical strings are
+    /// translated everywhere
+    pub fn locales_missing_any(
+        &mut self,
+        required: &[&str],
+    ) -> HashMap<LanguageIdentifier, Vec<String>> {
+        self.preload_all();
+
+        let mut missing = HashMap::new();
+        for locale in &self.available_locales {
+            let absent: Vec<String> = required
+                .iter()
+                .filter(|key| {
+                    !self
+                        .bundles
+                        .get(locale)
+                        .is_some_and(|bundle| bundle.has_message(key))
+                })
+                .map(|key| key.to_string())
+                .collect();
+
+            if !absent.is_empty() {
+                missing.insert(locale.clone(), absent);
+            }
+        }
+
+        missing
+    }
+
+    /// The ids of every message in a locale's bundle, sorted, loading the bundle
+    /// if it isn't loaded yet
+    pub fn message_ids(&mut self, locale: &LanguageIdentifier) -> Result<Vec<String>, IntlError> {
+        if !self.has_bundle(locale) {
+            self.try_load_bundle(locale)?;
+        }
+
+        let (Some(ftl), Some(bundle)) = (self.ftl_source(locale), self.bundles.get(locale)) else {
+            return Err(IntlError::NoBundle(locale.to_owned()));
+        };
+
+        let mut ids: Vec<String> = message_ids(ftl)
+            .into_iter()
+            .filter(|id| bundle.has_message(id))
+            .collect();
+        ids.sort();
+        ids.dedup();
+
+        Ok(ids)
+    }
+
+    /// Check that every message and term of `locale` only references messages,
+    /// attributes and terms that exist, since a dangling reference only fails when
+    /// the message is formatted. Returns each broken reference as
+    /// `<id>: <reference>`, e.g. `welcome: -brand`, sorted
+    pub fn check_references(&mut self, locale: &LanguageIdentifier) -> Result<(), Vec<String>> {
+        if !self.has_bundle(locale) {
+            self.try_load_bundle(locale)
+                .map_err(|err| vec![err.to_string()])?;
+        }
+
+        let Some(bundle) = self.bundles.get(locale) else {
+            return Err(vec![IntlError::NoBundle(locale.to_owned()).to_string()]);
+        };
+
+        let resources: Vec<FluentResource> = self
+            .ftl_source(locale)
+            .into_iter()
+            .chain(
+                self.override_ftls
+                    .get(locale)
+                    .into_iter()
+                    .flatten()
+                    .map(String::as_str),
+            )
+            .map(|ftl| match FluentResource::try_new(ftl.to_owned()) {
+                Ok(resource) => resource,
+                Err((resource, _errors)) => resource,
+            })
+            .collect();
+
+        let terms: HashMap<&str, Vec<&str>> = resources
+            .iter()
+            .flat_map(|resource| resource.entries())
+            .filter_map(|entry| match entry {
+                ast::Entry::Term(term) => Some((
+                    term.id.name,
+                    term.attributes.iter().map(|attr| attr.id.name).collect(),
+                )),
+                _ => None,
+            })
+            .collect();
+
+        let mut broken = BTreeSet::new();
+        for entry in resources.iter().flat_map(|resource| resource.entries()) {
+            let (id, value, attributes) = match entry {
+                ast::Entry::Message(message) => (
+                    message.id.name.to_owned(),
+                    message.value.as_ref(),
+                    &message.attributes,
+                ),
+                ast::Entry::Term(term) => (
+                    format!("-{}", term.id.name),
+                    Some(&term.value),
+                    &term.attributes,
+                ),
+                _ => continue,
+            };
+
+            let mut references = BTreeSet::new();
+            for pattern in value
+                .into_iter()
+                .chain(attributes.iter().map(|attr| &attr.value))
+            {
+                collect_pattern_references(pattern, &mut references);
+            }
+
+            for reference in references {
+                let exists = match reference {
+                    Reference::Message(name, attribute) => {
+                        bundle.get_message(name).is_some_and(|message| {
+                            attribute
+                                .is_none_or(|attribute| message.get_attribute(attribute).is_some())
+                        })
+                    }
+                    Reference::Term(name, attribute) => terms.get(name).is_some_and(|attributes| {
+                        attribute.is_none_or(|attribute| attributes.contains(&attribute))
+                    }),
+                };
+
+                if !exists {
+                    broken.insert(format!("{id}: {reference}"));
+                }
+            }
+        }
+
+        if broken.is_empty() {
+            Ok(())
+        } else {
+            Err(broken.into_iter().collect())
+        }
+    }
+
+    /// An ftl template for translating into a new language: every message and term
+    /// of the fallback locale with its comment and its fallback text, to be
+    /// replaced by the translator
+    pub fn export_template(&self) -> String {
+        let Some(ftl) = self.ftl_source(&self.fallback_locale) else {
+            return String::new();
+        };
+        let resource = match fluent_syntax::parser::parse(ftl) {
+            Ok(resource) => resource,
+            Err((resource, _errors)) => resource,
+        };
+
+        let header = format!(
+            "Translation template generated from {}. Replace the text after each `=` with its translation",
+            self.fallback_locale
+        );
+        let mut body = vec![ast::Entry::ResourceComment(ast::Comment {
+            content: vec![header.as_str()],
+        })];
+        body.extend(
+            resource
+                .body
+                .into_iter()
+                .filter(|entry| matches!(entry, ast::Entry::Message(_) | ast::Entry::Term(_))),
+        );
+
+        fluent_syntax::serializer::serialize(&ast::Resource { body })
+    }
+
+    /// The ids of the fallback locale's messages which aren't in `used`, e.g. the
+    /// keys requested while exercising the app, so dead translations can be pruned
+    pub fn unused_keys(&mut self, used: &HashSet<String>) -> Vec<String> {
+        let fallback = self.fallback_locale.clone();
+        match self.message_ids(&fallback) {
+            Ok(ids) => ids.into_iter().filter(|id| !used.contains(id)).collect(),
+            Err(err) => {
+                tracing::error!("could not list the message ids of {fallback}: {err}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Diffs the message ids of every available locale against the fallback locale,
+    /// e.g. to fail CI when a locale falls too far behind
+    pub fn coverage_report(&self) -> HashMap<LanguageIdentifier, Coverage> {
+        let reference: BTreeSet<String> = self
+            .ftl_source(&self.fallback_locale)
+            .map(message_ids)
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+
+        self.available_locales
+            .iter()
+            .map(|locale| {
+                let ids: BTreeSet<String> = self
+                    .ftl_source(locale)
+                    .map(message_ids)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .collect();
+
+                let missing: Vec<String> = reference.difference(&ids).cloned().collect();
+                let extra: Vec<String> = ids.difference(&reference).cloned().collect();
+                let completion = if reference.is_empty() {
+                    100.0
+                } else {
+                    100.0 * (reference.len() - missing.len()) as f32 / reference.len() as f32
+                };
+
+                (
+                    locale.clone(),
+                    Coverage {
+                        missing,
+                        extra,
+                        completion,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    fn export_csv_header(&self) -> String {
+        let mut fields = vec!["key".to_owned()];
+        fields.extend(self.available_locales.iter().map(|l| l.to_string()));
+        csv_line(&fields)
+    }
+
+    fn export_csv_row(&self, key: &str) -> String {
+        let mut fields = vec![key.to_owned()];
+        for locale in &self.available_locales {
+            let value = self
+                .bundles
+                .get(locale)
+                .and_then(|bundle| {
+                    let pattern = bundle.get_message(key)?.value()?;
+                    let mut errors = vec![];
+                    Some(
+                        bundle
+                            .format_pattern(pattern, None, &mut errors)
+                            .to_string(),
+                    )
+                })
+                .unwrap_or_default();
+            fields.push(value);
+        }
+        csv_line(&fields)
+    }
+
+    /// Gets the available locales a speaker of `locale` would likely accept, e.g.
+    /// es-ES for es-419. Useful for building a fallback chain
+    pub fn related_locales(&self, locale: &LanguageIdentifier) -> Vec<LanguageIdentifier> {
+        RELATED_LOCALES
+            .iter()
+            .filter_map(|(a, b)| {
+                if a == locale {
+                    Some(b)
+                } else if b == locale {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+            .filter(|related| self.available_locales.contains(related))
+            .cloned()
+            .collect()
+    }
+
+    /// Whether the current locale is written right-to-left
+    pub fn is_rtl(&self) -> bool {
+        is_locale_rtl(&self.current_locale)
+    }
+
+    /// Concatenates strings assembled in rust rather than fluent, e.g. a translated
+    /// prefix and a user value, isolating each part so it lays out correctly in the
+    /// current locale's direction. No marks are added when isolation is disabled
+    pub fn concat_directional(&self, parts: &[&str]) -> String {
+        if !self.use_isolating {
+            return parts.concat();
+        }
+
+        let rtl = self.is_rtl();
+        let mut result = String::new();
+        if rtl {
+            result.push(RIGHT_TO_LEFT_ISOLATE);
+        }
+        for part in parts {
+            result.push(FIRST_STRONG_ISOLATE);
+            result.push_str(part);
+            result.push(POP_DIRECTIONAL_ISOLATE);
+        }
+        if rtl {
+            result.push(POP_DIRECTIONAL_ISOLATE);
+        }
+
+        result
+    }
+
+    /// Folds a string for accent-insensitive searching, e.g. "Café" -> "cafe" and
+    /// "Straße" -> "strasse". Only latin-style diacritics are removed so marks which
+    /// change meaning in other scripts (like japanese dakuten) are kept. Under turkish
+    /// and azerbaijani the dotted and dotless i stay distinct
+    pub fn fold_for_search(&self, s: &str) -> String {
+        let language = self.current_locale.language;
+        let turkic = language == "tr" || language == "az";
+
+        let mut lowered = String::with_capacity(s.len());
+        for ch in s.chars() {
+            match ch {
+                'I' if turkic => lowered.push('ı'),
+                'İ' if turkic => lowered.push('i'),
+                'ß' | 'ẞ' => lowered.push_str("ss"),
+                _ => lowered.extend(ch.to_lowercase()),
+            }
+        }
+
+        lowered
+            .nfd()
+            .filter(|ch| !COMBINING_DIACRITICS.contains(ch))
+            .nfc()
+            .collect()
+    }
+
+    /// The available locale the region of `locale` is grouped under, if `locale`
+    /// itself isn't available. See [`REGION_GROUPS`]
+    fn region_group(&self, locale: &LanguageIdentifier) -> Option<&LanguageIdentifier> {
+        if self.available_locales.contains(locale) {
+            return None;
+        }
+
+        let region = locale.region?;
+        REGION_GROUPS
+            .iter()
+            .find(|(language, regions, _)| {
+                locale.language.as_str() == *language && regions.contains(&region.as_str())
+            })
+            .and_then(|(_, _, group)| self.available_locales.iter().find(|l| *l == group))
+    }
+
+    /// The available locale written in the same script as `locale`, if `locale`
+    /// itself isn't available and its language is available in more than one
+    /// script, e.g. zh-Hant-HK and zh-HK pick traditional zh-TW over simplified zh-CN.
+    /// The script is inferred from the region when `locale` doesn't have one
+    fn script_match(&self, locale: &LanguageIdentifier) -> Option<&LanguageIdentifier> {
+        if self.available_locales.contains(locale) {
+            return None;
+        }
+
+        let script_of = |locale: &LanguageIdentifier| {
+            let mut maximized = locale.clone();
+            maximized.maximize();
+            maximized.script
+        };
+
+        let candidates: Vec<(&LanguageIdentifier, _)> = self
+            .available_locales
+            .iter()
+            .filter(|available| available.language == locale.language)
+            .map(|available| (available, script_of(available)))
+            .collect();
+        if candidates
+            .iter()
+            .all(|(_, script)| *script == candidates[0].1)
+        {
+            return None;
+        }
+
+        let script = script_of(locale)?;
+        candidates
+            .into_iter()
+            .find(|(_, candidate_script)| *candidate_script == Some(script))
+            .map(|(available, _)| available)
+    }
+
+    /// Negotiates the best locale from a list of preferred locales
+    pub fn negotiate_locale(&self, preferred: &[LanguageIdentifier]) -> LanguageIdentifier {
+        self.negotiate(preferred).chosen
+    }
+
+    /// How the current locale was negotiated from the preferred locales passed to
+    /// [`Localization::with_preferences`], for diagnosing why the app shows up in
+    /// the wrong language. Without preferences it reports the default locale
+    pub fn negotiation_report(&self) -> NegotiationReport {
+        self.negotiation_report.clone()
+    }
+
+    /// Only negotiate the preferred locales as given, without substituting an
+    /// available locale of the same region group or script for ones that aren't
+    /// available. A preferred locale that doesn't match anything then yields the
+    /// fallback locale
+    pub fn set_strict_negotiation(&mut self, strict: bool) {
+        self.strict_negotiation = strict;
+    }
+
+    fn negotiate(&self, requested: &[LanguageIdentifier]) -> NegotiationReport {
+        let mut substitutions = Vec::new();
+        let preferred: Vec<LanguageIdentifier> = requested
+            .iter()
+            .map(|locale| {
+                let substitute = if self.strict_negotiation {
+                    None
+                } else {
+                    self.region_group(locale)
+                        .or_else(|| self.script_match(locale))
+                };
+
+                match substitute {
+                    Some(substitute) => {
+                        substitutions.push((locale.clone(), substitute.clone()));
+                        substitute.clone()
+                    }
+                    None => locale.clone(),
+                }
+            })
+            .collect();
+        let available = self.available_locales.clone();
+        let negotiated = negotiate_languages(
+            &preferred,
+            &available,
+            Some(&self.fallback_locale),
+            fluent_langneg::NegotiationStrategy::Filtering,
+        );
+        let chosen = negotiated
+            .first()
+            .map_or(self.fallback_locale.clone(), |v| (*v).clone());
+
+        let reason = if requested.contains(&chosen) {
+            NegotiationReason::ExactMatch
+        } else if requested
+            .iter()
+            .any(|locale| locale.language == chosen.language)
+        {
+            NegotiationReason::LanguageMatch
+        } else {
+            NegotiationReason::Default
+        };
+
+        NegotiationReport {
+            requested: requested.to_vec(),
+            substitutions,
+            fallback: self.fallback_locale.clone(),
+            chosen,
+            reason,
+        }
+    }
+}
+
+/// Ftl parsed on a background thread, see [`Localization::preload_all_async`]
+pub struct ParsedFtl {
+    locale: LanguageIdentifier,
+    resource: FluentResource,
+    errors: Vec<String>,
+}
+
+/// How a locale was picked from a list of preferred locales, see
+/// [`Localization::negotiation_report`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NegotiationReport {
+    /// The preferred locales, in order, e.g. as detected from the system
+    pub requested: Vec<LanguageIdentifier>,
+    /// Preferred locales that weren't available, each with the available locale of
+    /// the same region group or script that was negotiated in its place
+    pub substitutions: Vec<(LanguageIdentifier, LanguageIdentifier)>,
+    /// The locale used when no preferred locale matches
+    pub fallback: LanguageIdentifier,
+    pub chosen: LanguageIdentifier,
+    pub reason: NegotiationReason,
+}
+
+/// Why [`NegotiationReport::chosen`] was picked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegotiationReason {
+    /// A preferred locale is available as is
+    ExactMatch,
+    /// Only the language of a preferred locale is available, e.g. es-MX picked es-419
+    LanguageMatch,
+    /// No preferred locale matched, so the fallback locale was used
+    Default,
+}
+
+/// Statistics about cache usage
+#[derive(Debug, Clone)]
+pub struct CacheStats {
+    pub resource_cache_size: usize,
+    pub string_cache_size: usize,
+    pub cached_locales: Vec<LanguageIdentifier>,
+    /// Lookups answered from the string cache
+    pub hits: u64,
+    /// Lookups that had to format the string
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// The fraction of lookups answered from the string cache, 0 if there were none
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+/// How much of the fallback locale a locale translates, see
+/// [`Localization::coverage_report`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coverage {
+    /// Message ids in the fallback locale that this locale lacks
+    pub missing: Vec<String>,
+    /// Message ids in this locale that the fallback locale doesn't have
+    pub extra: Vec<String>,
+    /// Percentage of the fallback locale's messages this locale has, 0 to 100
+    pub completion: f32,
+}
+
+/// The cached strings for a single locale, tracking how recently each one was
+/// used so that only the least recently used entries are evicted when it grows
+/// past its limit. Recency is bumped through atomics so cache hits only need `&self`.
+#[derive(Default)]
+struct StringCache {
+    entries: HashMap<String, CachedString>,
+    clock: AtomicU64,
+}
+
+struct CachedString {
+    value: String,
+    last_used: AtomicU64,
+}
+
+impl StringCache {
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn get(&self, key: &str) -> Option<&str> {
+        let entry = self.entries.get(key)?;
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        Some(&entry.value)
+    }
+
+    fn insert(&mut self, key: String, value: String) {
+        let last_used = AtomicU64::new(self.tick());
+        self.entries.insert(key, CachedString { value, last_used });
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Evicts the least recently used entries until at most `max` remain,
+    /// returning how many were evicted
+    fn evict_to(&mut self, max: usize) -> usize {
+        let excess = self.entries.len().saturating_sub(max);
+        if excess == 0 {
+            return 0;
+        }
+
+        let mut by_recency: Vec<(u64, String)> = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (entry.last_used.load(Ordering::Relaxed), key.clone()))
+            .collect();
+        by_recency.sort_unstable();
+
+        for (_, key) in by_recency.into_iter().take(excess) {
+            self.entries.remove(&key);
+        }
+
+        excess
+    }
+}
+
+/// A deterministic hash of formatting arguments, or None if an argument can't be
+/// hashed (custom values)
+fn hash_args(args: &FluentArgs) -> Option<u64> {
+    let mut hasher = DefaultHasher::new();
+
+    // FluentArgs keeps its entries sorted by name
+    for (name, value) in args.iter() {
+        name.hash(&mut hasher);
+        match value {
+            FluentValue::String(string) => {
+                0u8.hash(&mut hasher);
+                string.hash(&mut hasher);
+            }
+            FluentValue::Number(number) => {
+                1u8.hash(&mut hasher);
+                number.value.to_bits().hash(&mut hasher);
+                number.options.hash(&mut hasher);
+            }
+            FluentValue::None => 2u8.hash(&mut hasher),
+            FluentValue::Error => 3u8.hash(&mut hasher),
+            FluentValue::Custom(_) => return None,
+        }
+    }
+
+    Some(hasher.finish())
+}
+
+/// Pads `text` with accented filler to `factor` times its length in chars
+fn expand_pseudolocalized(text: &str, factor: f32) -> String {
+    let len = text.chars().count();
+    let target = (len as f32 * factor).ceil() as usize;
+    if target <= len {
+        return text.to_owned();
+    }
+
+    let mut expanded = String::with_capacity(text.len() * 2);
+    expanded.push_str(text);
+    expanded.extend(PSEUDOLOCALE_FILLER.chars().cycle().take(target - len));
+    expanded
+}
+
+/// Whether a locale is written right-to-left, e.g. arabic, hebrew, persian and urdu
+pub fn is_locale_rtl(locale: &LanguageIdentifier) -> bool {
+    locale.character_direction() == CharacterDirection::RTL
+}
+
+/// Parse ftl source, logging and returning any errors along with the resource,
+/// which contains every entry that could be parsed
+fn parse_ftl(lang: &LanguageIdentifier, ftl: String) -> (FluentResource, Vec<String>) {
+    match FluentResource::try_new(ftl) {
+        Err((resource, parse_errors)) => {
+            let mut errors = Vec::with_capacity(parse_errors.len());
+            for error in parse_errors {
+                tracing::error!("load_bundle ({lang}): {error}");
+                errors.push(error.to_string());
+            }
+
+            tracing::warn!("load_bundle ({}: loading bundle with errors", lang);
+            (resource, errors)
+        }
+
+        Ok(resource) => {
+            tracing::info!("loaded {} bundle OK!", lang);
+            (resource, vec![])
+        }
+    }
+}
+
+/// The message ids in some ftl source, in file order
+fn message_ids(ftl: &str) -> Vec<String> {
+    let resource = match FluentResource::try_new(ftl.to_string()) {
+        Ok(resource) => resource,
+        Err((resource, _errors)) => resource,
+    };
+
+    resource
+        .entries()
+        .filter_map(|entry| match entry {
+            ast::Entry::Message(message) => Some(message.id.name.to_owned()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Collect the names of the variables referenced in a pattern
+fn collect_pattern_variables<'a>(pattern: &ast::Pattern<&'a str>, vars: &mut BTreeSet<&'a str>) {
+    for element in &pattern.elements {
+        if let ast::PatternElement::Placeable { expression } = element {
+            collect_expression_variables(expression, vars);
+        }
+    }
+}
+
+fn collect_expression_variables<'a>(
+    expression: &ast::Expression<&'a str>,
+    vars: &mut BTreeSet<&'a str>,
+) {
+    match expression {
+        ast::Expression::Select { selector, variants } => {
+            collect_inline_variables(selector, vars);
+            for variant in variants {
+                collect_pattern_variables(&variant.value, vars);
+            }
+        }
+        ast::Expression::Inline(inline) => collect_inline_variables(inline, vars),
+    }
+}
+
+fn collect_inline_variables<'a>(
+    inline: &ast::InlineExpression<&'a str>,
+    vars: &mut BTreeSet<&'a str>,
+) {
+    let arguments = match inline {
+        ast::InlineExpression::VariableReference { id } => {
+            vars.insert(id.name);
+            return;
+        }
+        ast::InlineExpression::Placeable { expression } => {
+            collect_expression_variables(expression, vars);
+            return;
+        }
+        ast::InlineExpression::FunctionReference { arguments, .. } => arguments,
+        ast::InlineExpression::TermReference {
+            arguments: Some(arguments),
+            ..
+        } => arguments,
+        _ => return,
+    };
+
+    for positional in &arguments.positional {
+        collect_inline_variables(positional, vars);
+    }
+    for named in &arguments.named {
+        collect_inline_variables(&named.value, vars);
+    }
+}
+
+/// A reference from one message or term to another, with the attribute it uses
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Reference<'a> {
+    Message(&'a str, Option<&'a str>),
+    Term(&'a str, Option<&'a str>),
+}
+
+impl std::fmt::Display for Reference<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (prefix, name, attribute) = match self {
+            Reference::Message(name, attribute) => ("", name, attribute),
+            Reference::Term(name, attribute) => ("-", name, attribute),
+        };
+
+        write!(f, "{prefix}{name}")?;
+        if let Some(attribute) = attribute {
+            write!(f, ".{attribute}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect the messages and terms referenced in a pattern
+fn collect_pattern_references<'a>(
+    pattern: &ast::Pattern<&'a str>,
+    refs: &mut BTreeSet<Reference<'a>>,
+) {
+    for element in &pattern.elements {
+        if let ast::PatternElement::Placeable { expression } = element {
+            collect_expression_references(expression, refs);
+        }
+    }
+}
+
+fn collect_expression_references<'a>(
+    expression: &ast::Expression<&'a str>,
+    refs: &mut BTreeSet<Reference<'a>>,
+) {
+    match expression {
+        ast::Expression::Select { selector, variants } => {
+            collect_inline_references(selector, refs);
+            for variant in variants {
+                collect_pattern_references(&variant.value, refs);
+            }
+        }
+        ast::Expression::Inline(inline) => collect_inline_references(inline, refs),
+    }
+}
+
+fn collect_inline_references<'a>(
+    inline: &ast::InlineExpression<&'a str>,
+    refs: &mut BTreeSet<Reference<'a>>,
+) {
+    let arguments = match inline {
+        ast::InlineExpression::MessageReference { id, attribute } => {
+            refs.insert(Reference::Message(
+                id.name,
+                attribute.as_ref().map(|attr| attr.name),
+            ));
+            return;
+        }
+        ast::InlineExpression::TermReference {
+            id,
+            attribute,
+            arguments,
+        } => {
+            refs.insert(Reference::Term(
+                id.name,
+                attribute.as_ref().map(|attr| attr.name),
+            ));
+            let Some(arguments) = arguments else {
+                return;
+            };
+            arguments
+        }
+        ast::InlineExpression::Placeable { expression } => {
+            collect_expression_references(expression, refs);
+            return;
+        }
+        ast::InlineExpression::FunctionReference { arguments, .. } => arguments,
+        _ => return,
+    };
+
+    for positional in &arguments.positional {
+        collect_inline_references(positional, refs);
+    }
+    for named in &arguments.named {
+        collect_inline_references(&named.value, refs);
+    }
+}
+
+/// Join fields into a CSV line, quoting fields that need it
+fn csv_line(fields: &[String]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+/// Replace each invalid character with exactly one underscore
+/// This matches the behavior of the Python extraction script
+pub fn fixup_key(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.chars() {
+        match ch {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' => out.push(ch),
+            _ => out.push('_'), // always push
+        }
+    }
+    let trimmed = out.trim_matches('_');
+    trimmed.to_owned()
+}
+
+/// Like [`fixup_key`], but keeps the meaning of non-ASCII letters while still
+/// producing a valid fluent identifier: accents are stripped ("Café" becomes
+/// "Cafe") and other letters, like CJK, are written as their `u<hex>` code point
+pub fn fixup_key_transliterated(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for ch in s.nfd().filter(|ch| !COMBINING_DIACRITICS.contains(ch)) {
+        match ch {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' => out.push(ch),
+            _ if ch.is_alphanumeric() => out.push_str(&format!("u{:x}", ch as u32)),
+            _ => out.push('_'),
+        }
+    }
+    let trimmed = out.trim_matches('_');
+    trimmed.to_owned()
+}
+
+/// Roughly reverse [`fixup_key`] for display: drops the `_<hash>` suffix of
+/// `hash_bytes` bytes added by [`Localization::normalized_ftl_key`] and turns
+/// underscores back into spaces. A key whose last word happens to be hex letters
+/// of the hash's length (like "decade") loses it
+pub fn humanize_key(key: &str, hash_bytes: usize) -> String {
+    let key = match key.rsplit_once('_') {
+        Some((rest, hash))
+            if hash.len() == hash_bytes * 2 && hash.chars().all(|c| c.is_ascii_hexdigit()) =>
+        {
+            rest
+        }
+        _ => key,
+    };
+
+    key.split('_')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The first `bytes` bytes of the md5 digest of `s` as hex
+fn simple_hash(s: &str, bytes: usize) -> String {
+    let digest = md5::compute(s.as_bytes());
+    hex::encode(&digest[..bytes.min(digest.len())])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bool_localized() {
+        let mut i18n = Localization::no_bidi();
+        assert_eq!(i18n.format_bool(true), "On");
+        assert_eq!(i18n.format_bool(false), "Off");
+        assert_eq!(i18n.format_yes_no(true), "Yes");
+        assert_eq!(i18n.format_yes_no(false), "No");
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.format_bool(true), "An");
+        assert_eq!(i18n.format_bool(false), "Aus");
+        assert_eq!(i18n.format_yes_no(true), "Ja");
+        assert_eq!(i18n.format_yes_no(false), "Nein");
+    }
+
+    #[test]
+    fn test_on_bundle_loaded() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let loaded = Arc::new(AtomicUsize::new(0));
+        let mut i18n = Localization::no_bidi();
+        let counter = loaded.clone();
+        i18n.on_bundle_loaded(Box::new(move |_lang| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        }));
+
+        i18n.format_bool(true);
+        i18n.format_bool(false);
+        assert_eq!(loaded.load(Ordering::SeqCst), 1);
+
+        i18n.set_locale(DE).unwrap();
+        i18n.format_bool(true);
+        assert_eq!(loaded.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_export_key_map() {
+        let mut i18n = Localization::no_bidi();
+        i18n.normalized_ftl_key("Add account", "Button label to add a new account");
+        i18n.normalized_ftl_key("Add Account", "Column title for adding new account");
+        i18n.normalized_ftl_key("{count}s", "Relative time in seconds");
+
+        let map = i18n.export_key_map();
+        let key =
+            |source: &str, comment: &str| map[&(source.to_owned(), comment.to_owned())].clone();
+        assert_eq!(map.len(), 3);
+        assert_eq!(
+            key("Add account", "Button label to add a new account"),
+            "Add_account_1cfc87"
+        );
+        assert_eq!(
+            key("Add Account", "Column title for adding new account"),
+            "Add_Account_d06c6a"
+        );
+        assert_eq!(
+            key("{count}s", "Relative time in seconds"),
+            "count_s_aa26bc"
+        );
+    }
+
+    #[test]
+    fn test_normalized_key_per_comment() {
+        let mut i18n = Localization::no_bidi();
+        let button = i18n.normalized_ftl_key("Save", "button");
+        let menu = i18n.normalized_ftl_key("Save", "menu");
+        assert_ne!(button, menu);
+
+        // cached lookups keep them apart
+        assert_eq!(i18n.normalized_ftl_key("Save", "button"), button);
+        assert_eq!(i18n.normalized_ftl_key("Save", "menu"), menu);
+        assert!(i18n.audit_key_collisions().is_empty());
+    }
+
+    #[test]
+    fn test_missing_placeholder() {
+        let mut i18n = Localization::no_bidi();
+        i18n.set_missing_placeholder(|s| format!("⟦{s}⟧"));
+
+        let missing = tr!(i18n, "Not in any bundle", "Test string with no translation");
+        assert_eq!(missing, "⟦Not in any bundle⟧");
+
+        let missing = tr!(
+            i18n,
+            "Hello {name}",
+            "Test string with no translation",
+            name = "Alice"
+        );
+        assert_eq!(missing, "⟦Hello Alice⟧");
+
+        assert_eq!(i18n.format_bool(true), "On");
+    }
+
+    #[test]
+    fn test_write_export_csv_matches_export_all_csv() {
+        let mut i18n = Localization::no_bidi();
+        let expected = i18n.export_all_csv();
+
+        let mut out: Vec<u8> = vec![];
+        i18n.write_export_csv(&mut out).unwrap();
+        let streamed = String::from_utf8(out).unwrap();
+
+        assert_eq!(streamed, expected);
+        assert!(streamed.starts_with("key,en-US,en-XA,de,"));
+        assert!(streamed.contains("\nAccounts_f01844,Accounts,"));
+    }
+
+    #[test]
+    fn test_related_locales() {
+        let i18n = Localization::default();
+        assert_eq!(i18n.related_locales(&ES_419), vec![ES_ES]);
+        assert_eq!(i18n.related_locales(&PT_PT), vec![PT_BR]);
+        assert!(i18n.related_locales(&EN_US).is_empty());
+    }
+
+    #[test]
+    fn test_locales_missing_any() {
+        let mut i18n = Localization::no_bidi();
+        let missing = i18n.locales_missing_any(&["Accounts_f01844", "On_ec510c"]);
+
+        assert!(!missing.contains_key(&EN_US));
+        assert!(!missing.contains_key(&DE));
+        assert_eq!(missing[&ES_ES], vec!["On_ec510c".to_string()]);
+    }
+
+    #[test]
+    fn test_coverage_report() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (locale, ftl) in [
+            ("en-US", "first = First\nsecond = Second\n"),
+            ("eo", "first = Unua\nonly_eo = Nur\n"),
+        ] {
+            let locale_dir = dir.path().join(locale);
+            fs::create_dir_all(&locale_dir).unwrap();
+            fs::write(locale_dir.join("main.ftl"), ftl).unwrap();
+        }
+
+        let i18n = Localization::from_dir(dir.path()).unwrap();
+        let report = i18n.coverage_report();
+
+        let en_us = &report[&EN_US];
+        assert!(en_us.missing.is_empty());
+        assert!(en_us.extra.is_empty());
+        assert_eq!(en_us.completion, 100.0);
+
+        let eo = &report[&langid!("eo")];
+        assert_eq!(eo.missing, vec!["second".to_owned()]);
+        assert_eq!(eo.extra, vec!["only_eo".to_owned()]);
+        assert_eq!(eo.completion, 50.0);
+
+        assert_eq!(report.len(), i18n.get_available_locales().len());
+    }
+
+    #[test]
+    fn test_concat_directional() {
+        assert_eq!(
+            Localization::no_bidi().concat_directional(&["Name: ", "Alice"]),
+            "Name: Alice"
+        );
+
+        let mut i18n = Localization::default();
+        assert_eq!(
+            i18n.concat_directional(&["Name: ", "Alice"]),
+            "\u{2068}Name: \u{2069}\u{2068}Alice\u{2069}"
+        );
+
+        // no rtl locale ships yet, so set it directly
+        i18n.current_locale = langid!("ar");
+        assert_eq!(
+            i18n.concat_directional(&["اسم: ", "Alice"]),
+            "\u{2067}\u{2068}اسم: \u{2069}\u{2068}Alice\u{2069}\u{2069}"
+        );
+    }
+
+    #[test]
+    fn test_fold_for_search() {
+        let mut i18n = Localization::default();
+        assert_eq!(i18n.fold_for_search("Café Crème"), "cafe creme");
+        assert_eq!(i18n.fold_for_search("São Paulo"), "sao paulo");
+        assert_eq!(i18n.fold_for_search("Ελληνικά"), "ελληνικα");
+        assert_eq!(i18n.fold_for_search("İstanbul"), "istanbul");
+        // dakuten change the meaning of kana so they are kept
+        assert_eq!(i18n.fold_for_search("がぎ"), "がぎ");
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.fold_for_search("Straße Über"), "strasse uber");
+
+        // no turkish locale ships yet, so set it directly
+        i18n.current_locale = langid!("tr");
+        assert_eq!(i18n.fold_for_search("IŞIK"), "ısık");
+        assert_eq!(i18n.fold_for_search("İzmir"), "izmir");
+    }
+
+    #[test]
+    fn test_unsupplied_variables() {
+        let mut i18n = Localization::no_bidi();
+
+        // "{count}s", looked up without its count
+        let seconds = IntlKeyBuf::new("count_s_aa26bc");
+        i18n.get_cached_string(seconds.borrow(), None).unwrap();
+
+        // "{count}m", looked up with its count
+        let minutes = IntlKeyBuf::new("count_m_b41e53");
+        let mut args = FluentArgs::new();
+        args.set("count", 2);
+        i18n.get_cached_string(minutes.borrow(), Some(&args))
+            .unwrap();
+
+        assert_eq!(
+            i18n.required_variables(minutes.borrow()),
+            Some(vec!["count".to_string()])
+        );
+        assert_eq!(
+            i18n.unsupplied_variables(),
+            vec![("count_s_aa26bc".to_string(), vec!["count".to_string()])]
+        );
+    }
+
+    #[test]
+    fn test_from_dir() {
+        let dir = tempfile::TempDir::new().unwrap();
+        for (locale, ftl) in [
+            ("en-US", "only_on_disk = From disk\n"),
+            ("eo", "only_on_disk = El disko\n"),
+        ] {
+            let locale_dir = dir.path().join(locale);
+            fs::create_dir_all(&locale_dir).unwrap();
+            fs::write(locale_dir.join("main.ftl"), ftl).unwrap();
+        }
+
+        let mut i18n = Localization::from_dir(dir.path()).unwrap();
+        let key = IntlKeyBuf::new("only_on_disk");
+        assert_eq!(i18n.get_string(key.borrow()).unwrap(), "From disk");
+
+        // locales that aren't on disk still use the baked in ftl
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.format_bool(true), "An");
+        // ...and fall back to the en-US on disk
+        assert_eq!(i18n.get_string(key.borrow()).unwrap(), "From disk");
+
+        let eo: LanguageIdentifier = langid!("eo");
+        assert!(i18n.get_available_locales().contains(&eo));
+        i18n.set_locale(eo).unwrap();
+        assert_eq!(i18n.get_string(key.borrow()).unwrap(), "El disko");
+    }
+
+    #[test]
+    fn test_set_fallback_locale() {
+        let mut i18n = Localization::no_bidi();
+        assert!(i18n.set_fallback_locale(langid!("xx")).is_err());
+        assert_eq!(i18n.get_fallback_locale(), &EN_US);
+
+        i18n.set_fallback_locale(DE).unwrap();
+        assert_eq!(i18n.get_fallback_chain(&ES_419), &[ES_ES, DE]);
+        assert_eq!(i18n.negotiate_locale(&[langid!("xx")]), DE);
+
+        // a locale without any messages resolves everything through the fallback
+        let eo = langid!("eo");
+        i18n.register_locale(eo.clone(), "Esperanto".to_owned(), String::new())
+            .unwrap();
+        i18n.set_locale(eo).unwrap();
+        assert_eq!(i18n.format_bool(true), "An");
+    }
+
+    #[test]
+    fn test_from_ftl_map() {
+        let mut i18n = Localization::from_ftl_map(HashMap::from([
+            (
+                EN_US,
+                "test_key = Test\nanother_key = Another { $name }\n".to_owned(),
+            ),
+            (DE, "test_key = Prüfung\n".to_owned()),
+        ]))
+        .unwrap();
+
+        assert_eq!(i18n.get_available_locales(), &[DE, EN_US]);
+        assert_eq!(i18n.get_current_locale(), &EN_US);
+        assert_eq!(i18n.get_string(IntlKey::new("test_key")).unwrap(), "Test");
+        // nothing from the baked in translations
+        assert!(i18n.get_string(IntlKey::new("On_ec510c")).is_err());
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(
+            i18n.get_string(IntlKey::new("test_key")).unwrap(),
+            "Prüfung"
+        );
+        let mut args = FluentArgs::new();
+        args.set("name", "Welt");
+        assert_eq!(
+            i18n.get_cached_string(IntlKey::new("another_key"), Some(&args))
+                .unwrap(),
+            "Another \u{2068}Welt\u{2069}"
+        );
+        assert!(i18n.set_locale(FR).is_err());
+
+        let err = Localization::from_ftl_map(HashMap::from([(DE, "broken = {\n".to_owned())]));
+        assert!(matches!(err, Err(IntlError::ParseErrors(locale, _)) if locale == DE));
+    }
+
+    #[test]
+    fn test_register_locale() {
+        let it = langid!("it");
+        let mut i18n = Localization::no_bidi();
+        assert!(i18n.set_locale(it.clone()).is_err());
+
+        let err = i18n
+            .register_locale(it.clone(), "Italiano".to_owned(), "broken = {\n".to_owned())
+            .unwrap_err();
+        assert!(matches!(err, IntlError::ParseErrors(locale, _) if locale == it));
+        assert!(!i18n.get_available_locales().contains(&it));
+
+        i18n.register_locale(
+            it.clone(),
+            "Italiano".to_owned(),
+            "On_ec510c = Attivo\ngreeting = Ciao { $name }\n".to_owned(),
+        )
+        .unwrap();
+        assert_eq!(i18n.get_locale_native_name(&it), Some("Italiano"));
+
+        i18n.set_locale(it).unwrap();
+        assert_eq!(i18n.format_bool(true), "Attivo");
+        let mut args = FluentArgs::new();
+        args.set("name", "Marco");
+        assert_eq!(
+            i18n.get_cached_string(IntlKey::new("greeting"), Some(&args))
+                .unwrap(),
+            "Ciao Marco"
+        );
+        // untranslated strings fall back to en-US
+        assert_eq!(i18n.format_bool(false), "Off");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let broken = "ok = Fine\nbroken = { $unclosed\n";
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let locale_dir = dir.path().join("eo");
+        fs::create_dir_all(&locale_dir).unwrap();
+        fs::write(locale_dir.join("main.ftl"), broken).unwrap();
+
+        match Localization::from_dir(dir.path()) {
+            Err(IntlError::ParseErrors(lang, errors)) => {
+                assert_eq!(lang, langid!("eo"));
+                assert!(!errors.is_empty());
+            }
+            Err(err) => panic!("expected parse errors, got {err}"),
+            Ok(_) => panic!("expected parse errors"),
+        }
+
+        // bundles loaded lazily still load leniently, with their errors queryable
+        let eo = langid!("eo");
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(eo.clone(), broken.to_owned());
+        assert!(i18n.bundle_errors(&eo).is_empty());
+        i18n.set_locale(eo.clone()).unwrap();
+        assert_eq!(i18n.get_string(IntlKey::new("ok")).unwrap(), "Fine");
+        assert!(!i18n.bundle_errors(&eo).is_empty());
+        assert!(i18n.bundle_errors(&EN_US).is_empty());
+    }
+
+    #[test]
+    fn test_validate_all() {
+        let i18n = Localization::default();
+        if let Err(failures) = i18n.validate_all() {
+            for (locale, err) in &failures {
+                eprintln!("{locale}: {err}");
+            }
+            panic!("{} baked in ftl failures", failures.len());
+        }
+
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(
+            langid!("eo"),
+            "dangling = { missing-message }\nbroken = { $unclosed\n".to_owned(),
+        );
+        let failures = i18n.validate_all().unwrap_err();
+        assert_eq!(failures.len(), 2);
+        assert!(failures
+            .iter()
+            .any(|(_, err)| matches!(err, IntlError::ParseErrors(..))));
+        assert!(failures.iter().any(
+            |(_, err)| matches!(err, IntlError::FormatErrors(key, _) if key.borrow().as_str() == "dangling")
+        ));
+    }
+
+    #[test]
+    fn test_fluent_functions() {
+        let ftl = "big = { NUMBER($n) }\nplain = { NUMBER($n, useGrouping: \"false\") }\nshout = { SHOUT($word) }\n";
+
+        let mut i18n = Localization::no_bidi();
+        i18n.add_runtime_ftl(EN_US, ftl.to_owned());
+        i18n.add_runtime_ftl(DE, ftl.to_owned());
+        i18n.add_function("SHOUT", |positional, _named| match positional.first() {
+            Some(FluentValue::String(word)) => FluentValue::from(word.to_uppercase()),
+            _ => FluentValue::Error,
+        });
+
+        let mut args = FluentArgs::new();
+        args.set("n", 1234567);
+        args.set("word", "hey");
+
+        let big = IntlKey::new("big");
+        let plain = IntlKey::new("plain");
+        assert_eq!(
+            i18n.get_cached_string(big, Some(&args)).unwrap(),
+            "1,234,567"
+        );
+        assert_eq!(
+            i18n.get_cached_string(plain, Some(&args)).unwrap(),
+            "1234567"
+        );
+        assert_eq!(
+            i18n.get_cached_string(IntlKey::new("shout"), Some(&args))
+                .unwrap(),
+            "HEY"
+        );
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(
+            i18n.get_cached_string(big, Some(&args)).unwrap(),
+            "1.234.567"
+        );
+    }
+
+    #[test]
+    fn test_fallback_chain() {
+        let mut i18n = Localization::no_bidi();
+        assert_eq!(i18n.get_fallback_chain(&ES_419), &[ES_ES, EN_US]);
+        assert_eq!(i18n.get_fallback_chain(&ZH_TW), &[ZH_CN, EN_US]);
+        assert_eq!(i18n.get_fallback_chain(&DE), &[EN_US]);
+
+        i18n.add_runtime_ftl(ES_ES, "only_in_es_es = Solo en España\n".to_owned());
+        i18n.set_locale(ES_419).unwrap();
+
+        let key = IntlKeyBuf::new("only_in_es_es");
+        assert_eq!(i18n.get_string(key.borrow()).unwrap(), "Solo en España");
+
+        // keys missing from the whole chain still end up in en-US
+        assert_eq!(i18n.format_bool(true), "On");
+    }
+
+    #[test]
+    fn test_persisted_locale() {
+        let path = DataPath::new(tempfile::TempDir::new().unwrap().path());
+        let settings = path.path(DataPathType::Setting);
+
+        // nothing persisted yet
+        assert_eq!(
+            Localization::from_data_path(&path).get_current_locale(),
+            &EN_US
+        );
+
+        write_file(&settings, LOCALE_FILE.to_owned(), "de\n").unwrap();
+        let mut i18n = Localization::from_data_path(&path);
+        assert_eq!(i18n.get_current_locale(), &DE);
+
+        i18n.set_locale_persisted(FR, &path).unwrap();
+        assert_eq!(
+            Localization::from_data_path(&path).get_current_locale(),
+            &FR
+        );
+
+        // a locale that isn't available anymore is replaced
+        write_file(&settings, LOCALE_FILE.to_owned(), "xx-YY").unwrap();
+        assert_eq!(
+            Localization::from_data_path(&path).get_current_locale(),
+            &EN_US
+        );
+        assert_eq!(
+            Directory::new(settings)
+                .get_file(LOCALE_FILE.to_owned())
+                .unwrap(),
+            "en-US"
+        );
+    }
+
+    #[test]
+    fn test_is_rtl() {
+        let mut i18n = Localization::default();
+        for locale in i18n.get_available_locales() {
+            assert!(!is_locale_rtl(locale), "{locale} should be ltr");
+        }
+        assert!(!i18n.is_rtl());
+
+        for locale in ["ar", "he", "fa", "ur", "ar-EG"] {
+            let locale: LanguageIdentifier = locale.parse().unwrap();
+            assert!(is_locale_rtl(&locale), "{locale} should be rtl");
+        }
+
+        i18n.current_locale = langid!("he");
+        assert!(i18n.is_rtl());
+    }
+
+    #[test]
+    fn test_get_string_resolved() {
+        let mut i18n = Localization::no_bidi();
+        i18n.set_locale(FR).unwrap();
+
+        // only translated in en-US so far
+        let (text, resolved) = i18n.get_string_resolved(IntlKey::new("On_ec510c")).unwrap();
+        assert_eq!(text, "On");
+        assert_eq!(resolved, EN_US);
+
+        let (_, resolved) = i18n
+            .get_string_resolved(IntlKey::new("Accounts_f01844"))
+            .unwrap();
+        assert_eq!(resolved, FR);
+    }
+
+    #[test]
+    fn test_pseudolocale_expansion() {
+        let mut i18n = Localization::no_bidi();
+        let id = IntlKey::new("Accounts_f01844");
+        let english = i18n.get_string(id).unwrap();
+
+        i18n.set_locale(EN_XA).unwrap();
+        let pseudo = i18n.get_string(id).unwrap();
+
+        i18n.set_pseudolocale_expansion(2.0);
+        let expanded = i18n.get_string(id).unwrap();
+        assert!(expanded.starts_with(&pseudo));
+        assert_eq!(expanded.chars().count(), pseudo.chars().count() * 2);
+        // cached in expanded form
+        assert_eq!(i18n.get_string(id).unwrap(), expanded);
+
+        i18n.set_locale(EN_US).unwrap();
+        assert_eq!(i18n.get_string(id).unwrap(), english);
+    }
+
+    #[test]
+    fn test_get_attribute() {
+        let mut i18n = Localization::no_bidi();
+        i18n.add_runtime_ftl(
+            EN_US,
+            "search = Search\n    .aria-label = Search notes by { $author }\n".to_owned(),
+        );
+
+        let id = IntlKey::new("search");
+        let mut args = FluentArgs::new();
+        args.set("author", "jb55");
+
+        assert_eq!(
+            i18n.get_attribute(id, "aria-label", Some(&args)).unwrap(),
+            "Search notes by jb55"
+        );
+        assert_eq!(i18n.get_string(id).unwrap(), "Search");
+        assert!(matches!(
+            i18n.get_attribute(id, "tooltip", None),
+            Err(IntlError::NoAttribute(_, attr)) if attr == "tooltip"
+        ));
+        assert!(matches!(
+            i18n.get_attribute(IntlKey::new("nope"), "tooltip", None),
+            Err(IntlError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_negotiate_region_groups() {
+        let i18n = Localization::default();
+        for (preferred, expected) in [
+            ("es-MX", ES_419),
+            ("es-AR", ES_419),
+            ("es-CL", ES_419),
+            ("es-ES", ES_ES),
+            ("es-GQ", ES_ES),
+        ] {
+            let preferred: LanguageIdentifier = preferred.parse().unwrap();
+            assert_eq!(i18n.negotiate_locale(&[preferred]), expected);
+        }
+
+        // regardless of the order locales were made available in
+        let mut i18n = Localization::default();
+        i18n.available_locales.retain(|l| *l != ES_419);
+        i18n.available_locales.push(ES_419);
+        assert_eq!(i18n.negotiate_locale(&[langid!("es-MX")]), ES_419);
+        assert_eq!(i18n.negotiate_locale(&[langid!("es-AD")]), ES_ES);
+    }
+
+    #[test]
+    fn test_negotiate_script() {
+        let i18n = Localization::default();
+        for (preferred, expected) in [
+            ("zh-Hant-HK", ZH_TW),
+            ("zh-Hant", ZH_TW),
+            ("zh-HK", ZH_TW),
+            ("zh-Hans-SG", ZH_CN),
+            ("zh-SG", ZH_CN),
+            ("zh", ZH_CN),
+            ("zh-TW", ZH_TW),
+        ] {
+            let preferred: LanguageIdentifier = preferred.parse().unwrap();
+            assert_eq!(i18n.negotiate_locale(&[preferred]), expected);
+        }
+
+        // languages available in a single script negotiate as before
+        assert_eq!(i18n.negotiate_locale(&[langid!("pt-AO")]).language, "pt");
+        assert_eq!(i18n.negotiate_locale(&[langid!("de-AT")]), DE);
+    }
+
+    #[test]
+    fn test_strict_negotiation() {
+        let mut i18n = Localization::default();
+        let uk = langid!("uk");
+
+        // no locale is synthesized for an unavailable language in either mode
+        for strict in [false, true] {
+            i18n.set_strict_negotiation(strict);
+            assert_eq!(i18n.negotiate_locale(std::slice::from_ref(&uk)), EN_US);
+            let report = i18n.negotiate(std::slice::from_ref(&uk));
+            assert!(report.substitutions.is_empty());
+            assert_eq!(report.reason, NegotiationReason::Default);
+        }
+
+        // only lenient negotiation substitutes the traditional Chinese locale
+        i18n.set_strict_negotiation(false);
+        let report = i18n.negotiate(&[langid!("zh-HK")]);
+        assert_eq!(report.substitutions, [(langid!("zh-HK"), ZH_TW)]);
+        assert_eq!(report.chosen, ZH_TW);
+
+        i18n.set_strict_negotiation(true);
+        assert!(i18n.negotiate(&[langid!("zh-HK")]).substitutions.is_empty());
+        assert_eq!(i18n.negotiate_locale(&[DE]), DE);
+    }
+
+    #[test]
+    fn test_negotiation_report() {
+        let report = Localization::default().negotiation_report();
+        assert!(report.requested.is_empty());
+        assert_eq!(report.chosen, EN_US);
+        assert_eq!(report.reason, NegotiationReason::Default);
+
+        let i18n = Localization::with_preferences(&[langid!("eo"), langid!("es-MX"), DE]);
+        assert_eq!(
+            i18n.negotiation_report(),
+            NegotiationReport {
+                requested: vec![langid!("eo"), langid!("es-MX"), DE],
+                substitutions: vec![(langid!("es-MX"), ES_419)],
+                fallback: EN_US,
+                chosen: ES_419,
+                reason: NegotiationReason::LanguageMatch,
+            }
         );
-        self.current_locale = locale;
 
-        // Clear caches when locale changes since they are locale-specific
-        self.string_cache.clear();
-        tracing::debug!("String cache cleared due to locale change");
+        let i18n = Localization::with_preferences(&[DE]);
+        assert_eq!(
+            i18n.negotiation_report().reason,
+            NegotiationReason::ExactMatch
+        );
 
-        Ok(())
+        let i18n = Localization::with_preferences(&[langid!("eo")]);
+        let report = i18n.negotiation_report();
+        assert_eq!(report.chosen, EN_US);
+        assert_eq!(report.reason, NegotiationReason::Default);
     }
 
-    /// Clears the parsed FluentResource cache (useful for development when FTL files change)
-    pub fn clear_cache(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        self.bundles.clear();
-        tracing::debug!("Parsed FluentResource cache cleared");
+    #[test]
+    fn test_locale_options() {
+        let mut i18n = Localization::default();
+        i18n.available_locales.push(langid!("eo"));
 
-        self.string_cache.clear();
-        tracing::debug!("String result cache cleared");
+        let options = i18n.locale_options();
+        assert_eq!(options.len(), i18n.get_available_locales().len());
+        for locale in i18n.get_available_locales() {
+            assert!(options.iter().any(|(l, _)| l == locale));
+        }
 
-        Ok(())
+        let names: Vec<&str> = options.iter().map(|(_, name)| name.as_ref()).collect();
+        let position = |name| names.iter().position(|n| *n == name).unwrap();
+        assert!(position("Deutsch") < position("English (US)"));
+        assert!(position("English (US)") < position("Español (España)"));
+        assert!(position("Español (Latinoamérica)") < position("Français"));
+        assert!(position("eo") < position("Español (España)"));
+        assert!(position("Português (Portugal)") < position("日本語"));
+
+        // the current locale's tailoring applies, ä sorts after z in Swedish
+        let xx = langid!("xx");
+        i18n.available_locales.push(xx.clone());
+        i18n.locale_native_names
+            .insert(xx.clone(), "Äppel".to_owned());
+        let positions = |i18n: &Localization| {
+            let options = i18n.locale_options();
+            let xx = options.iter().position(|(l, _)| *l == xx).unwrap();
+            let de = options.iter().position(|(l, _)| *l == DE).unwrap();
+            (xx, de)
+        };
+        let (xx_pos, de_pos) = positions(&i18n);
+        assert!(xx_pos < de_pos);
+        i18n.current_locale = langid!("sv");
+        let (xx_pos, de_pos) = positions(&i18n);
+        assert!(xx_pos > de_pos);
     }
 
-    /// Gets the current locale
-    pub fn get_current_locale(&self) -> &LanguageIdentifier {
-        &self.current_locale
+    #[test]
+    fn test_missing_key_warned_once() {
+        let mut i18n = Localization::default();
+        let id = IntlKey::new("not_a_real_key");
+
+        for _ in 0..3 {
+            assert!(matches!(i18n.get_string(id), Err(IntlError::NotFound(_))));
+        }
+        assert_eq!(i18n.warned_missing_keys[&EN_US].len(), 1);
+
+        i18n.set_locale(DE).unwrap();
+        assert!(matches!(i18n.get_string(id), Err(IntlError::NotFound(_))));
+        assert_eq!(i18n.warned_missing_keys[&DE].len(), 1);
+
+        i18n.reset_missing_key_warnings();
+        assert!(i18n.warned_missing_keys.is_empty());
+        assert!(matches!(i18n.get_string(id), Err(IntlError::NotFound(_))));
     }
 
-    /// Gets all available locales
-    pub fn get_available_locales(&self) -> &[LanguageIdentifier] {
-        &self.available_locales
+    #[test]
+    fn test_message_ids() {
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(
+            langid!("eo"),
+            "zeta = Z\nalpha = A\n-term = T\nbroken = { $\n".to_owned(),
+        );
+
+        assert_eq!(
+            i18n.message_ids(&langid!("eo")).unwrap(),
+            vec!["alpha", "zeta"]
+        );
+
+        let ids = i18n.message_ids(&EN_US).unwrap();
+        assert!(ids.contains(&"On_ec510c".to_owned()));
+        assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert!(i18n.message_ids(&langid!("xx")).is_err());
     }
 
-    /// Gets the fallback locale
-    pub fn get_fallback_locale(&self) -> &LanguageIdentifier {
-        &self.fallback_locale
+    #[test]
+    fn test_check_references() {
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(
+            langid!("eo"),
+            concat!(
+                "-brand = Notedeck\n",
+                "    .gender = neuter\n",
+                "about = Pri { -brand }\n",
+                "welcome = Bonvenon al { -missing }\n",
+                "greeting = { about } kaj { about.title }\n",
+                "-tagline = { nowhere }\n",
+                "gendered = { -brand.gender ->\n",
+                "    [neuter] Ĝi\n",
+                "   *[other] Li\n",
+                "}\n",
+            )
+            .to_owned(),
+        );
+
+        assert_eq!(
+            i18n.check_references(&langid!("eo")),
+            Err(vec![
+                "-tagline: nowhere".to_owned(),
+                "greeting: about.title".to_owned(),
+                "welcome: -missing".to_owned(),
+            ])
+        );
+        assert_eq!(i18n.check_references(&EN_US), Ok(()));
     }
 
-    pub fn get_locale_native_name(&self, locale: &LanguageIdentifier) -> Option<&str> {
-        self.locale_native_names.get(locale).map(|s| s.as_str())
+    #[test]
+    fn test_preload_all() {
+        let mut i18n = Localization::default();
+        i18n.preload_all();
+        assert_eq!(
+            i18n.get_cache_stats().unwrap().resource_cache_size,
+            i18n.get_available_locales().len()
+        );
     }
 
-    /// Gets cache statistics for monitoring performance
-    pub fn get_cache_stats(&self) -> Result<CacheStats, Box<dyn std::error::Error + Send + Sync>> {
-        let mut total_strings = 0;
-        for locale_cache in self.string_cache.values() {
-            total_strings += locale_cache.len();
-        }
+    #[test]
+    fn test_preload_all_async() {
+        let mut i18n = Localization::no_bidi();
+        i18n.get_string(IntlKey::new("On_ec510c")).unwrap();
 
-        Ok(CacheStats {
-            resource_cache_size: self.bundles.len(),
-            string_cache_size: total_strings,
-            cached_locales: self.bundles.keys().cloned().collect(),
-        })
+        let parsed = i18n.preload_all_async().recv().unwrap();
+        assert_eq!(parsed.len(), i18n.get_available_locales().len() - 1);
+        i18n.insert_parsed(parsed);
+
+        assert_eq!(
+            i18n.get_cache_stats().unwrap().resource_cache_size,
+            i18n.get_available_locales().len()
+        );
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.get_string(IntlKey::new("On_ec510c")).unwrap(), "An");
     }
 
-    /// Limits the string cache size to prevent memory growth
-    pub fn limit_string_cache_size(
-        &mut self,
-        max_strings_per_locale: usize,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        for locale_cache in self.string_cache.values_mut() {
-            if locale_cache.len() > max_strings_per_locale {
-                // Remove oldest entries (simple approach: just clear and let it rebuild)
-                // In a more sophisticated implementation, you might use an LRU cache
-                locale_cache.clear();
-                tracing::debug!("Cleared string cache for locale due to size limit");
-            }
-        }
+    #[test]
+    fn test_get_string_or_key() {
+        let mut i18n = Localization::no_bidi();
+        assert_eq!(i18n.get_string_or_key(IntlKey::new("On_ec510c")), "On");
+        assert_eq!(
+            i18n.get_string_or_key(IntlKey::new("Zap_this__note_1a2b3c")),
+            "Zap this note"
+        );
+        assert_eq!(
+            i18n.get_string_or_key(IntlKey::new("no_hash_here")),
+            "no hash here"
+        );
 
-        Ok(())
+        // keys with a longer hash are humanized too
+        i18n.set_key_hash_bytes(4);
+        let key = i18n.normalized_ftl_key("Zap this note", "Button label");
+        assert_eq!(i18n.get_string_or_key(key.borrow()), "Zap this note");
     }
 
-    /// Negotiates the best locale from a list of preferred locales
-    pub fn negotiate_locale(&self, preferred: &[LanguageIdentifier]) -> LanguageIdentifier {
-        let available = self.available_locales.clone();
-        let negotiated = negotiate_languages(
-            preferred,
-            &available,
-            Some(&self.fallback_locale),
-            fluent_langneg::NegotiationStrategy::Filtering,
+    #[test]
+    fn test_cache_hit_miss_metrics() {
+        let mut i18n = Localization::default();
+        let id = IntlKey::new("On_ec510c");
+
+        i18n.get_string(id).unwrap();
+        i18n.get_string(id).unwrap();
+
+        let stats = i18n.get_cache_stats().unwrap();
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.hit_ratio(), 0.5);
+
+        // switching locales clears strings but not the counters
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.get_cache_stats().unwrap().hits, 1);
+
+        i18n.clear_cache().unwrap();
+        let stats = i18n.get_cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses), (0, 0));
+        assert_eq!(stats.hit_ratio(), 0.0);
+    }
+
+    #[test]
+    fn test_key_hash_width() {
+        // the md5 digests of these comments share their first 2 bytes only
+        let (a, b) = ("Button label 62", "Button label 251");
+
+        let mut narrow = Localization::default();
+        narrow.set_key_hash_bytes(2);
+        assert_eq!(
+            narrow.normalized_ftl_key("Add", a),
+            IntlKeyBuf::new("Add_a347")
+        );
+        let mut narrow = Localization::default();
+        narrow.set_key_hash_bytes(2);
+        assert_eq!(
+            narrow.normalized_ftl_key("Add", b),
+            IntlKeyBuf::new("Add_a347")
+        );
+
+        let mut wide = Localization::default();
+        assert_eq!(
+            wide.normalized_ftl_key("Add", a),
+            IntlKeyBuf::new("Add_a347fa")
+        );
+        let mut wide = Localization::default();
+        assert_eq!(
+            wide.normalized_ftl_key("Add", b),
+            IntlKeyBuf::new("Add_a34782")
         );
-        negotiated
-            .first()
-            .map_or(self.fallback_locale.clone(), |v| (*v).clone())
     }
-}
 
-/// Statistics about cache usage
-#[derive(Debug, Clone)]
-pub struct CacheStats {
-    pub resource_cache_size: usize,
-    pub string_cache_size: usize,
-    pub cached_locales: Vec<LanguageIdentifier>,
-}
+    #[cfg(debug_assertions)]
+    #[test]
+    fn test_key_collision_tracked() {
+        let mut i18n = Localization::default();
+        i18n.normalized_ftl_key("Add!", "Button label");
+        i18n.normalized_ftl_key("Add?", "Button label");
 
-#[cfg(test)]
-mod tests {
+        // both normalize to the same key, only the first source is kept
+        assert_eq!(i18n.normalized_key_sources.len(), 1);
+        let (source, _) = i18n.normalized_key_sources.values().next().unwrap();
+        assert_eq!(source, "Add!");
+    }
+
+    #[test]
+    fn test_fixup_key_transliterated() {
+        assert_eq!(fixup_key("Café"), "Caf");
+        assert_eq!(fixup_key_transliterated("Café"), "Cafe");
+        assert_eq!(fixup_key_transliterated("Ñandú crème"), "Nandu_creme");
+        assert_eq!(fixup_key_transliterated("你好"), "u4f60u597d");
+        assert_eq!(fixup_key_transliterated("Hi, 世界!"), "Hi__u4e16u754c");
+
+        let mut i18n = Localization::default();
+        i18n.set_transliterate_keys(true);
+        let key = i18n.normalized_ftl_key("你好", "Greeting");
+        assert!(key.to_string().starts_with("u4f60u597d_"));
+
+        i18n.set_transliterate_keys(false);
+        let key = i18n.normalized_ftl_key("你好", "Greeting");
+        assert!(key.to_string().starts_with("k__"));
+    }
+
+    #[test]
+    fn test_audit_key_collisions() {
+        let mut i18n = Localization::default();
+        let key = i18n.normalized_ftl_key("Hello, world!", "Greeting");
+        i18n.normalized_ftl_key("Hello. world?", "Greeting");
+        i18n.normalized_ftl_key("Goodbye", "Greeting");
+
+        assert_eq!(
+            i18n.audit_key_collisions(),
+            vec![(
+                key.to_string(),
+                vec!["Hello, world!".to_owned(), "Hello. world?".to_owned()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_add_override() {
+        let mut i18n = Localization::default();
+        let on = IntlKey::new("On_ec510c");
+        let off = IntlKey::new("Off_7b79c1");
+        assert_eq!(i18n.get_string(on).unwrap(), "On");
+
+        i18n.add_override(EN_US, "On_ec510c = Enabled\n").unwrap();
+        assert_eq!(i18n.get_string(on).unwrap(), "Enabled");
+        assert_eq!(i18n.get_string(off).unwrap(), "Off");
+
+        // survives switching away and back
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.get_string(on).unwrap(), "An");
+        i18n.set_locale(EN_US).unwrap();
+        assert_eq!(i18n.get_string(on).unwrap(), "Enabled");
+
+        assert!(i18n.add_override(EN_US, "broken = { $\n").is_err());
+
+        i18n.clear_cache().unwrap();
+        assert_eq!(i18n.get_string(on).unwrap(), "On");
+    }
+
+    #[test]
+    fn test_export_template() {
+        let mut i18n = Localization::default();
+        let template = i18n.export_template();
+
+        assert!(FluentResource::try_new(template.clone()).is_ok());
+        let mut ids = message_ids(&template);
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids, i18n.message_ids(&EN_US).unwrap());
+
+        assert!(template
+            .contains("# Column title for account management\nAccounts_f01844 = Accounts\n"));
+    }
+
+    #[test]
+    fn test_unused_keys() {
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(EN_US, "used = Used\nstale = Stale\nold = Old\n".to_owned());
+
+        let used = HashSet::from(["used".to_owned()]);
+        assert_eq!(i18n.unused_keys(&used), vec!["old", "stale"]);
+    }
+
+    #[test]
+    fn test_is_translated() {
+        let mut i18n = Localization::default();
+        i18n.add_override(EN_US, "only_in_en_us = Only in English\n")
+            .unwrap();
+        let only_en = IntlKey::new("only_in_en_us");
+        let on = IntlKey::new("On_ec510c");
+
+        assert!(i18n.is_translated(only_en));
+        assert!(i18n.is_translated(on));
+
+        i18n.set_locale(DE).unwrap();
+        assert!(!i18n.is_translated(only_en));
+        assert!(i18n.is_translated(on));
+        // still resolves through the fallback
+        assert_eq!(i18n.get_string(only_en).unwrap(), "Only in English");
+    }
+
+    #[test]
+    fn test_duplicate_ids() {
+        let mut i18n = Localization::default();
+        i18n.available_locales.push(langid!("eo"));
+        i18n.runtime_ftls.insert(
+            langid!("eo"),
+            "greeting = Saluton\nfarewell = Ĝis\ngreeting = Duplikato\n".to_owned(),
+        );
+        i18n.set_locale(langid!("eo")).unwrap();
+
+        assert_eq!(
+            i18n.get_string(IntlKey::new("greeting")).unwrap(),
+            "Saluton"
+        );
+        assert_eq!(i18n.duplicate_ids(&langid!("eo")), ["greeting"]);
+        assert!(i18n.duplicate_ids(&EN_US).is_empty());
+    }
+
+    #[test]
+    fn test_has_message() {
+        let mut i18n = Localization::default();
+        assert!(!i18n.has_message(&EN_US, "On_ec510c"));
+
+        i18n.get_string(IntlKey::new("On_ec510c")).unwrap();
+        assert!(i18n.has_message(&EN_US, "On_ec510c"));
+        assert!(!i18n.has_message(&EN_US, "not_a_message"));
+        assert!(!i18n.has_message(&DE, "On_ec510c"));
+    }
+
+    #[test]
+    fn test_format_with_count() {
+        let mut i18n = Localization::no_bidi();
+        i18n.add_override(
+            EN_US,
+            "notes = { $count ->\n    [0] No notes\n    [one] {$count} note\n   *[other] {$count} notes\n}\n",
+        )
+        .unwrap();
+
+        let notes = IntlKey::new("notes");
+        assert_eq!(i18n.format_with_count(notes, 0).unwrap(), "No notes");
+        assert_eq!(i18n.format_with_count(notes, 1).unwrap(), "1 note");
+        assert_eq!(i18n.format_with_count(notes, 2).unwrap(), "2 notes");
+        assert_eq!(i18n.format_with_count(notes, 1000).unwrap(), "1,000 notes");
+    }
+
+    #[test]
+    fn test_format_number() {
+        let mut i18n = Localization::default();
+        assert_eq!(i18n.format_number(1234567.5), "1,234,567.5");
+        assert_eq!(i18n.format_number(-1000.0), "-1,000");
+        assert_eq!(i18n.format_number(999.0), "999");
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.format_number(1234567.5), "1.234.567,5");
+
+        i18n.set_locale(FR).unwrap();
+        assert_eq!(i18n.format_number(1234.5), "1\u{202F}234,5");
+    }
+
+    #[test]
+    fn test_sort_strings() {
+        let mut names = vec!["Zoe".to_owned(), "Ärger".to_owned(), "Anna".to_owned()];
+
+        let mut i18n = Localization::default();
+        i18n.set_locale(DE).unwrap();
+        i18n.sort_strings(&mut names);
+        assert_eq!(names, ["Anna", "Ärger", "Zoe"]);
+        assert_eq!(i18n.compare("Ärger", "Zoe"), std::cmp::Ordering::Less);
+
+        i18n.set_locale(EN_US).unwrap();
+        i18n.sort_strings(&mut names);
+        assert_eq!(names, ["Anna", "Ärger", "Zoe"]);
+
+        // Swedish sorts ä after z
+        i18n.register_locale(langid!("sv"), "Svenska".to_owned(), String::new())
+            .unwrap();
+        i18n.set_locale(langid!("sv")).unwrap();
+        i18n.sort_strings(&mut names);
+        assert_eq!(names, ["Anna", "Zoe", "Ärger"]);
+    }
+
+    #[test]
+    fn test_format_override() {
+        let datetime = chrono::NaiveDate::from_ymd_opt(2025, 3, 9)
+            .unwrap()
+            .and_hms_opt(14, 5, 0)
+            .unwrap();
+
+        let mut i18n = Localization::default();
+        i18n.set_format_override(
+            EN_US,
+            FormatOptions {
+                group_separator: Some('\''),
+                date_style: Some(format::DateStyle::Iso),
+                ..Default::default()
+            },
+        );
+        // the decimal separator wasn't overridden
+        assert_eq!(i18n.format_number(1234567.5), "1'234'567.5");
+        assert_eq!(i18n.format_datetime(&datetime, true), "2025-03-09 14:05");
+
+        // other locales keep their defaults
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.format_number(1234567.5), "1.234.567,5");
+        assert_eq!(i18n.format_datetime(&datetime, false), "09.03.2025");
+
+        i18n.set_locale(EN_US).unwrap();
+        i18n.set_format_override(EN_US, FormatOptions::default());
+        assert_eq!(i18n.format_number(1234567.5), "1,234,567.5");
+        assert_eq!(i18n.format_datetime(&datetime, true), "03/09/2025 2:05 PM");
+    }
+
+    #[test]
+    fn test_missing_bundle_uses_fallback() {
+        let eo = langid!("eo");
+        let mut i18n = Localization::default();
+        i18n.available_locales.push(eo.clone());
+        i18n.set_locale(eo).unwrap();
+        assert_eq!(i18n.get_string(IntlKey::new("On_ec510c")).unwrap(), "On");
+
+        // no panic even when the fallback can't load either
+        let mut i18n = Localization::default();
+        i18n.available_locales.push(langid!("eo"));
+        i18n.set_locale(langid!("eo")).unwrap();
+        i18n.fallback_locale = langid!("xx");
+        assert!(matches!(
+            i18n.get_string(IntlKey::new("On_ec510c")),
+            Err(IntlError::NoFtl(_))
+        ));
+    }
+
+    #[test]
+    fn test_get_string_isolated() {
+        let mut i18n = Localization::default();
+        i18n.add_runtime_ftl(EN_US, "total = Total: { 5 }\n".to_owned());
+        let id = IntlKey::new("total");
+
+        let isolated = i18n.get_string_isolated(id, true).unwrap();
+        assert_eq!(isolated, "Total: \u{2068}5\u{2069}");
+        let plain = i18n.get_string_isolated(id, false).unwrap();
+        assert_eq!(plain, "Total: 5");
+
+        // both are cached without affecting each other or the default
+        assert_eq!(i18n.get_string_isolated(id, true).unwrap(), isolated);
+        assert_eq!(i18n.get_string_isolated(id, false).unwrap(), plain);
+        assert_eq!(i18n.get_string(id).unwrap(), isolated);
+
+        let mut i18n = Localization::no_bidi();
+        i18n.add_runtime_ftl(EN_US, "total = Total: { 5 }\n".to_owned());
+        assert_eq!(i18n.get_string_isolated(id, true).unwrap(), isolated);
+        assert_eq!(i18n.get_string(id).unwrap(), plain);
+    }
+
+    #[test]
+    fn test_with_preferences() {
+        let i18n = Localization::with_preferences(&[DE, FR]);
+        assert_eq!(i18n.get_current_locale(), &DE);
+
+        let i18n = Localization::with_preferences(&[langid!("xx"), FR]);
+        assert_eq!(i18n.get_current_locale(), &FR);
+
+        let i18n = Localization::with_preferences(&[]);
+        assert_eq!(
+            i18n.get_current_locale(),
+            Localization::new().get_current_locale()
+        );
+    }
+
+    #[test]
+    fn test_get_strings() {
+        let mut i18n = Localization::default();
+        i18n.set_locale(DE).unwrap();
+
+        let results = i18n.get_strings(&[
+            IntlKey::new("On_ec510c"),
+            IntlKey::new("not_a_real_key"),
+            IntlKey::new("Off_7b79c1"),
+        ]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), "An");
+        assert!(matches!(results[1], Err(IntlError::NotFound(_))));
+        assert_eq!(results[2].as_ref().unwrap(), "Aus");
+    }
+
+    #[test]
+    fn test_cache_strings_with_args() {
+        let mut i18n = Localization::no_bidi();
+        let id = IntlKey::new("count_s_aa26bc");
+
+        let mut args = FluentArgs::new();
+        args.set("count", 5);
+        assert_eq!(i18n.get_cached_string(id, Some(&args)).unwrap(), "5s");
+        assert_eq!(i18n.get_cache_stats().unwrap().string_cache_size, 0);
+
+        i18n.set_cache_strings_with_args(true);
+        assert_eq!(i18n.get_cached_string(id, Some(&args)).unwrap(), "5s");
+        assert_eq!(i18n.get_cached_string(id, Some(&args)).unwrap(), "5s");
+        assert_eq!(i18n.get_cache_stats().unwrap().string_cache_size, 1);
+
+        // equal arguments of different types are cached separately
+        let mut string_args = FluentArgs::new();
+        string_args.set("count", "5");
+        assert_eq!(
+            i18n.get_cached_string(id, Some(&string_args)).unwrap(),
+            "5s"
+        );
+        let mut other_args = FluentArgs::new();
+        other_args.set("count", 6);
+        assert_eq!(i18n.get_cached_string(id, Some(&other_args)).unwrap(), "6s");
+        assert_eq!(i18n.get_cache_stats().unwrap().string_cache_size, 3);
+
+        i18n.set_locale(DE).unwrap();
+        assert_eq!(i18n.get_cache_stats().unwrap().string_cache_size, 0);
+    }
+
+    #[test]
+    fn test_string_cache_evicts_least_recently_used() {
+        let mut i18n = Localization::default();
+        let max = 10;
+        let keys: Vec<String> = (0..max + 5).map(|i| format!("key_{i}")).collect();
+
+        for key in &keys {
+            i18n.cache_string(EN_US, IntlKey::new(key), key);
+        }
+
+        // a hit on the oldest entry should keep it alive
+        assert!(i18n
+            .lookup_cached_string(&EN_US, IntlKey::new(&keys[0]))
+            .is_ok());
+
+        i18n.limit_string_cache_size(max).unwrap();
+        assert_eq!(i18n.get_cache_stats().unwrap().string_cache_size, max);
+
+        assert!(i18n
+            .lookup_cached_string(&EN_US, IntlKey::new(&keys[0]))
+            .is_ok());
+        for key in &keys[1..6] {
+            assert!(i18n
+                .lookup_cached_string(&EN_US, IntlKey::new(key))
+                .is_err());
+        }
+        for key in &keys[6..] {
+            assert!(i18n.lookup_cached_string(&EN_US, IntlKey::new(key)).is_ok());
+        }
+    }
 
     //
     // TODO(jb55): write tests that work, i broke all these during the refacto
@@ -688,23 +3961,3 @@ mod tests {
     }
     */
 }
-
-/// Replace each invalid character with exactly one underscore
-/// This matches the behavior of the Python extraction script
-pub fn fixup_key(s: &str) -> String {
-    let mut out = String::with_capacity(s.len());
-    for ch in s.chars() {
-        match ch {
-            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' => out.push(ch),
-            _ => out.push('_'), // always push
-        }
-    }
-    let trimmed = out.trim_matches('_');
-    trimmed.to_owned()
-}
-
-fn simple_hash(s: &str) -> String {
-    let digest = md5::compute(s.as_bytes());
-    // Take the first 2 bytes and convert to 4 hex characters
-    format!("{:02x}{:02x}", digest[0], digest[1])
-}
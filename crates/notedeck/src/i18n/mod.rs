@@ -4,15 +4,25 @@
 //! It handles loading translation files, managing locales, and providing
 //! localized strings throughout the application.
 
+mod collate;
 mod error;
+mod format;
 mod key;
 pub mod manager;
+mod shared;
 
 pub use error::IntlError;
+pub use format::{DateStyle, FormatOptions};
 pub use key::{IntlKey, IntlKeyBuf};
 
+pub use manager::is_locale_rtl;
 pub use manager::CacheStats;
+pub use manager::Coverage;
 pub use manager::Localization;
+pub use manager::NegotiationReason;
+pub use manager::NegotiationReport;
+pub use manager::ParsedFtl;
+pub use shared::SharedLocalization;
 
 /// Re-export commonly used types for convenience
 pub use fluent::FluentArgs;
@@ -37,7 +47,7 @@ macro_rules! tr {
             match $i18n.get_string(key.borrow()) {
                 Ok(r) => r,
                 Err(_err) => {
-                    $message.to_string()
+                    $i18n.format_missing(&$message.to_string())
                 }
             }
         }
@@ -59,7 +69,7 @@ macro_rules! tr {
                     $(
                         result = result.replace(&format!("{{{}}}", stringify!($param)), &$value.to_string());
                     )*
-                    result
+                    $i18n.format_missing(&result)
                 }
             }
         }
@@ -90,12 +100,12 @@ macro_rules! tr_plural {
                     let mut result = $one.to_string();
                     $(result = result.replace(&format!("{{{}}}", stringify!($param)), &$value.to_string());)*
                     result = result.replace("{count}", &$count.to_string());
-                    result
+                    $i18n.format_missing(&result)
                 } else {
                     let mut result = $other.to_string();
                     $(result = result.replace(&format!("{{{}}}", stringify!($param)), &$value.to_string());)*
                     result = result.replace("{count}", &$count.to_string());
-                    result
+                    $i18n.format_missing(&result)
                 }
             }
         }
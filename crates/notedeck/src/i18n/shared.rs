@@ -0,0 +1,102 @@
+use super::{IntlError, IntlKey, Localization};
+use fluent::FluentArgs;
+use std::sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// A [`Localization`] that can be shared between threads, e.g. so background
+/// threads can format notification strings.
+///
+/// Lookups of strings that are already cached only take a read lock, so they
+/// don't block each other. Everything else (the first lookup of a string, loading
+/// bundles after a locale change, lookups that can't be cached) takes the write
+/// lock. The bundles of the current locale are loaded up front so that the write
+/// lock is only held briefly while warming the cache.
+#[derive(Clone)]
+pub struct SharedLocalization {
+    inner: Arc<RwLock<Localization>>,
+}
+
+impl SharedLocalization {
+    pub fn new(mut i18n: Localization) -> Self {
+        if let Err(err) = i18n.ensure_bundle() {
+            tracing::error!("SharedLocalization: could not preload bundles: {err}");
+        }
+
+        SharedLocalization {
+            inner: Arc::new(RwLock::new(i18n)),
+        }
+    }
+
+    /// Gets a localized string by its ID
+    pub fn get_string(&self, id: IntlKey<'_>) -> Result<String, IntlError> {
+        self.get_cached_string(id, None)
+    }
+
+    /// Gets a cached string result under the read lock, or takes the write lock
+    /// to format it and cache the result
+    pub fn get_cached_string(
+        &self,
+        id: IntlKey<'_>,
+        args: Option<&FluentArgs>,
+    ) -> Result<String, IntlError> {
+        if let Some(cached) = self.read().cached_string(id, args) {
+            return Ok(cached);
+        }
+
+        self.write().get_cached_string(id, args)
+    }
+
+    /// Lock the localization for reading, e.g. to check the current locale
+    pub fn read(&self) -> RwLockReadGuard<'_, Localization> {
+        self.inner.read().unwrap()
+    }
+
+    /// Lock the localization for writing, e.g. to change the locale
+    pub fn write(&self) -> RwLockWriteGuard<'_, Localization> {
+        self.inner.write().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use unic_langid::langid;
+
+    #[test]
+    fn test_concurrent_lookups() {
+        let shared = SharedLocalization::new(Localization::no_bidi());
+        shared.write().set_locale(langid!("de")).unwrap();
+
+        let threads: Vec<_> = (0..4)
+            .map(|i| {
+                let shared = shared.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        assert_eq!(shared.get_string(IntlKey::new("On_ec510c")).unwrap(), "An");
+                        assert_eq!(
+                            shared.get_string(IntlKey::new("Off_7b79c1")).unwrap(),
+                            "Aus"
+                        );
+
+                        let mut args = FluentArgs::new();
+                        args.set("count", i);
+                        assert_eq!(
+                            shared
+                                .get_cached_string(IntlKey::new("count_s_aa26bc"), Some(&args))
+                                .unwrap(),
+                            format!("{i}s")
+                        );
+                    }
+                })
+            })
+            .collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(
+            shared.read().get_cache_stats().unwrap().string_cache_size,
+            2
+        );
+    }
+}
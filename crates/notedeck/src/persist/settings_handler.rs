@@ -1,5 +1,6 @@
 use crate::{
     storage::delete_file, timed_serializer::TimedSerializer, DataPath, DataPathType, Directory,
+    Error,
 };
 use egui::ThemePreference;
 use serde::{Deserialize, Serialize};
@@ -121,18 +122,16 @@ impl SettingsHandler {
             return self;
         }
 
-        match self.directory.get_file(SETTINGS_FILE.to_string()) {
-            Ok(contents_str) => {
-                // Parse JSON content
-                match serde_json::from_str::<Settings>(&contents_str) {
-                    Ok(settings) => {
-                        self.current_settings = Some(settings);
-                    }
-                    Err(_) => {
-                        error!("Invalid settings format. Using defaults");
-                        self.current_settings = Some(Settings::default());
-                    }
-                }
+        match self
+            .directory
+            .read_json_lenient::<Settings>(SETTINGS_FILE.to_string())
+        {
+            Ok(settings) => {
+                self.current_settings = Some(settings);
+            }
+            Err(Error::Json(_)) => {
+                error!("Invalid settings format. Using defaults");
+                self.current_settings = Some(Settings::default());
             }
             Err(_) => {
                 error!("Could not read settings. Using defaults");
@@ -1,10 +1,13 @@
+use std::path::Path;
+
 use crate::{user_account::UserAccountSerializable, Result};
 use enostr::{Keypair, Pubkey, SerializableKeypair};
 use tokenator::{TokenParser, TokenSerializable, TokenWriter};
 
-use super::file_storage::{delete_file, write_file, Directory};
-
-static SELECTED_PUBKEY_FILE_NAME: &str = "selected_pubkey";
+use super::file_storage::{
+    backup_file_name, clear_selected_key, delete_file, read_selected_key, write_file_durable,
+    write_selected_key, Directory, SELECTED_KEY_FILE_NAME,
+};
 
 /// An OS agnostic file key storage implementation
 #[derive(Debug, PartialEq, Clone)]
@@ -41,7 +44,7 @@ impl AccountStorageWriter {
     pub fn write_account(&self, account: &UserAccountSerializable) -> Result<()> {
         let mut writer = TokenWriter::new("\t");
         account.serialize_tokens(&mut writer);
-        write_file(
+        write_file_durable(
             &self.storage.accounts_directory.file_path,
             account.key.pubkey.hex(),
             writer.str(),
@@ -53,25 +56,12 @@ impl AccountStorageWriter {
     }
 
     pub fn select_key(&self, pubkey: Option<Pubkey>) -> Result<()> {
+        let directory = &self.storage.selected_key_directory.file_path;
         if let Some(pubkey) = pubkey {
-            write_file(
-                &self.storage.selected_key_directory.file_path,
-                SELECTED_PUBKEY_FILE_NAME.to_owned(),
-                &serde_json::to_string(&pubkey.hex())?,
-            )
-        } else if self
-            .storage
-            .selected_key_directory
-            .get_file(SELECTED_PUBKEY_FILE_NAME.to_owned())
-            .is_ok()
-        {
-            // Case where user chose to have no selected pubkey, but one already exists
-            Ok(delete_file(
-                &self.storage.selected_key_directory.file_path,
-                SELECTED_PUBKEY_FILE_NAME.to_owned(),
-            )?)
+            // the backup lets read_selected_key_healed recover from a bad write
+            write_selected_key(directory, &serde_json::to_string(&pubkey.hex())?)
         } else {
-            Ok(())
+            clear_selected_key(directory)
         }
     }
 }
@@ -96,17 +86,74 @@ impl AccountStorageReader {
         Ok(keys)
     }
 
+    /// Get the names of account files with the same contents as another account
+    /// file, see [`dedupe_accounts`]
+    pub fn dedupe_accounts(&self) -> Result<Vec<String>> {
+        dedupe_accounts(&self.storage.accounts_directory)
+    }
+
     pub fn get_selected_key(&self) -> Result<Option<Pubkey>> {
-        match self
-            .storage
-            .selected_key_directory
-            .get_file(SELECTED_PUBKEY_FILE_NAME.to_owned())
-        {
-            Ok(pubkey_str) => Ok(Some(serde_json::from_str(&pubkey_str)?)),
-            Err(crate::Error::Io(_)) => Ok(None),
-            Err(e) => Err(e),
+        match read_selected_key(&self.storage.selected_key_directory.file_path)? {
+            Some(pubkey_str) => Ok(Some(serde_json::from_str(&pubkey_str)?)),
+            None => Ok(None),
         }
     }
+
+    /// Like [`AccountStorageReader::get_selected_key`], but repairs the selected key
+    /// file along the way, see [`read_selected_key_healed`]
+    pub fn read_selected_key_healed(&self) -> Result<Option<Pubkey>> {
+        read_selected_key_healed(&self.storage.selected_key_directory.file_path)
+    }
+}
+
+/// Get the names of account files in the accounts directory with the same
+/// contents as another account file, e.g. after importing the same key twice.
+/// Only files named by a pubkey are compared, so other files in the directory are
+/// never reported. They are only reported so a cleanup UI can decide what to remove.
+pub fn dedupe_accounts(directory: &Directory) -> Result<Vec<String>> {
+    directory.get_duplicate_files_where(|name| Pubkey::from_hex(name).is_ok())
+}
+
+/// Read the selected account from the [`DataPathType::SelectedKey`] directory,
+/// repairing the selected key file along the way. Stray whitespace is trimmed, and
+/// if the value is invalid while the `.bak` backup is valid, the backup is
+/// restored. In both cases a clean selected key file is written back, leaving the
+/// backup as it was.
+///
+/// [`DataPathType::SelectedKey`]: super::DataPathType::SelectedKey
+pub fn read_selected_key_healed(directory: &Path) -> Result<Option<Pubkey>> {
+    let Some(raw) = read_selected_key(directory)? else {
+        return Ok(None);
+    };
+
+    let pubkey = match parse_selected_key(&raw) {
+        Ok(pubkey) if raw.trim() == raw => return Ok(Some(pubkey)),
+        Ok(pubkey) => {
+            tracing::info!("trimmed stray whitespace from the selected key file");
+            pubkey
+        }
+        Err(err) => {
+            let backup = Directory::new(directory.to_path_buf())
+                .get_file(backup_file_name(SELECTED_KEY_FILE_NAME));
+            let Ok(pubkey) = backup.and_then(|backup| parse_selected_key(&backup)) else {
+                return Err(err);
+            };
+            tracing::warn!("selected key file was invalid, restored it from its backup");
+            pubkey
+        }
+    };
+
+    write_file_durable(
+        directory,
+        SELECTED_KEY_FILE_NAME.to_owned(),
+        &serde_json::to_string(&pubkey.hex())?,
+    )?;
+
+    Ok(Some(pubkey))
+}
+
+fn parse_selected_key(serialized: &str) -> Result<Pubkey> {
+    Ok(serde_json::from_str(serialized.trim())?)
 }
 
 fn deserialize_storage(serialized: &str) -> Result<UserAccountSerializable> {
@@ -133,6 +180,7 @@ mod tests {
 
     use super::Result;
     use super::*;
+    use crate::storage::write_file;
 
     static CREATE_TMP_DIR: fn() -> Result<PathBuf> =
         || Ok(tempfile::TempDir::new()?.path().to_path_buf());
@@ -196,4 +244,102 @@ mod tests {
             other => panic!("Expected Ok(None), got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_dedupe_accounts() {
+        let kp = enostr::FullKeypair::generate().to_keypair();
+        let storage = AccountStorage::mock().unwrap();
+        let (reader, writer) = storage.clone().rw();
+        writer
+            .write_account(&UserAccountSerializable::new(kp.clone()))
+            .unwrap();
+
+        let contents = storage
+            .accounts_directory
+            .get_file(kp.pubkey.hex())
+            .unwrap();
+        write_file(
+            &storage.accounts_directory.file_path,
+            format!("{}.imported", kp.pubkey.hex()),
+            &contents,
+        )
+        .unwrap();
+
+        // the copy isn't named by a pubkey, so it isn't an account file
+        assert!(reader.dedupe_accounts().unwrap().is_empty());
+
+        let other = enostr::FullKeypair::generate().to_keypair();
+        write_file(
+            &storage.accounts_directory.file_path,
+            other.pubkey.hex(),
+            &contents,
+        )
+        .unwrap();
+
+        let mut names = [kp.pubkey.hex(), other.pubkey.hex()];
+        names.sort();
+        assert_eq!(reader.dedupe_accounts().unwrap(), [names[1].clone()]);
+        assert_eq!(
+            dedupe_accounts(&storage.accounts_directory).unwrap(),
+            [names[1].clone()]
+        );
+    }
+
+    #[test]
+    fn test_read_selected_key_healed_trims_whitespace() {
+        let kp = enostr::FullKeypair::generate().to_keypair();
+        let storage = AccountStorage::mock().unwrap();
+        let reader = storage.clone().rw().0;
+        let directory = &storage.selected_key_directory;
+
+        write_file(
+            &directory.file_path,
+            SELECTED_KEY_FILE_NAME.to_owned(),
+            &format!("  \"{}\"\n\n", kp.pubkey.hex()),
+        )
+        .unwrap();
+
+        assert_eq!(reader.read_selected_key_healed().unwrap(), Some(kp.pubkey));
+        assert_eq!(
+            directory
+                .get_file(SELECTED_KEY_FILE_NAME.to_owned())
+                .unwrap(),
+            format!("\"{}\"", kp.pubkey.hex())
+        );
+    }
+
+    #[test]
+    fn test_read_selected_key_healed_restores_backup() {
+        let kp = enostr::FullKeypair::generate().to_keypair();
+        let storage = AccountStorage::mock().unwrap();
+        let reader = storage.clone().rw().0;
+        let directory = &storage.selected_key_directory;
+
+        write_file(
+            &directory.file_path,
+            SELECTED_KEY_FILE_NAME.to_owned(),
+            "\"not a pubkey",
+        )
+        .unwrap();
+        write_file(
+            &directory.file_path,
+            backup_file_name(SELECTED_KEY_FILE_NAME),
+            &format!("\"{}\"", kp.pubkey.hex()),
+        )
+        .unwrap();
+
+        assert!(reader.get_selected_key().is_err());
+        assert_eq!(
+            read_selected_key_healed(&directory.file_path).unwrap(),
+            Some(kp.pubkey)
+        );
+        assert_eq!(reader.get_selected_key().unwrap(), Some(kp.pubkey));
+        // the backup is left alone
+        assert_eq!(
+            directory
+                .get_file(backup_file_name(SELECTED_KEY_FILE_NAME))
+                .unwrap(),
+            format!("\"{}\"", kp.pubkey.hex())
+        );
+    }
 }
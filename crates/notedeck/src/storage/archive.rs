@@ -0,0 +1,113 @@
+use std::io::{Read, Write};
+
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+
+use super::{DataPath, DataPathType};
+use crate::Result;
+
+/// The data paths included in an archive. The database and caches are left out
+/// since they are large and can be rebuilt from relays.
+const ARCHIVED_PATHS: [DataPathType; 4] = [
+    DataPathType::Keys,
+    DataPathType::SelectedKey,
+    DataPathType::Setting,
+    DataPathType::Log,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArchiveFormat {
+    Tar,
+    TarGz,
+}
+
+/// Pack the accounts, settings and logs in the data directory into a single archive,
+/// for one-file backups and migrating to another device
+pub fn export_archive(data_path: &DataPath, out: impl Write, format: ArchiveFormat) -> Result<()> {
+    match format {
+        ArchiveFormat::Tar => {
+            write_archive(data_path, out)?;
+        }
+        ArchiveFormat::TarGz => {
+            let encoder = write_archive(data_path, GzEncoder::new(out, Compression::default()))?;
+            encoder.finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Unpack an archive created by [`export_archive`] into the data directory, overwriting
+/// existing files. File permissions are restored, so key files stay private on unix
+pub fn import_archive(data_path: &DataPath, input: impl Read, format: ArchiveFormat) -> Result<()> {
+    match format {
+        ArchiveFormat::Tar => read_archive(data_path, input),
+        ArchiveFormat::TarGz => read_archive(data_path, GzDecoder::new(input)),
+    }
+}
+
+fn write_archive<W: Write>(data_path: &DataPath, out: W) -> Result<W> {
+    let mut builder = tar::Builder::new(out);
+    builder.follow_symlinks(false);
+
+    for typ in ARCHIVED_PATHS {
+        let path = data_path.path(typ);
+        if path.is_dir() {
+            builder.append_dir_all(data_path.rel_path(typ), path)?;
+        }
+    }
+
+    Ok(builder.into_inner()?)
+}
+
+fn read_archive(data_path: &DataPath, input: impl Read) -> Result<()> {
+    let mut archive = tar::Archive::new(input);
+    archive.set_preserve_permissions(true);
+    archive.unpack(data_path.base())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{write_file, Directory};
+
+    #[test]
+    fn test_archive_round_trip() {
+        let from = DataPath::new(tempfile::TempDir::new().unwrap().path());
+        let to = DataPath::new(tempfile::TempDir::new().unwrap().path());
+
+        let keys = from.path(DataPathType::Keys);
+        write_file(&keys, "pubkey1".to_string(), "key one").unwrap();
+        write_file(&keys, "pubkey2".to_string(), "key two").unwrap();
+        let settings = from.path(DataPathType::Setting);
+        write_file(&settings, "settings.json".to_string(), "{}").unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let perms = std::fs::Permissions::from_mode(0o600);
+            std::fs::set_permissions(keys.join("pubkey1"), perms).unwrap();
+        }
+
+        let mut archive = vec![];
+        export_archive(&from, &mut archive, ArchiveFormat::TarGz).unwrap();
+        import_archive(&to, archive.as_slice(), ArchiveFormat::TarGz).unwrap();
+
+        for typ in [DataPathType::Keys, DataPathType::Setting] {
+            assert_eq!(
+                Directory::new(from.path(typ)).get_files().unwrap(),
+                Directory::new(to.path(typ)).get_files().unwrap()
+            );
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(to.path(DataPathType::Keys).join("pubkey1"))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+}
@@ -1,13 +1,32 @@
+use chacha20poly1305::{
+    aead::{Aead, AeadCore, KeyInit, OsRng},
+    XChaCha20Poly1305, XNonce,
+};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use hex::ToHex;
+use serde::de::DeserializeOwned;
+use sha2::Digest;
 use std::{
-    collections::{HashMap, VecDeque},
+    collections::{HashMap, HashSet, VecDeque},
+    ffi::{OsStr, OsString},
     fs::{self, File},
-    io::{self, BufRead},
+    io::{self, BufRead, Read, Seek, SeekFrom, Write},
     path::{Path, PathBuf},
-    time::SystemTime,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        RwLock,
+    },
+    time::{Duration, SystemTime},
 };
 
 use crate::{Error, Result};
 
+static DEFAULT_BASE_OVERRIDE: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Directories [`Directory::get_files`] already warned about case collisions in,
+/// so loading the accounts over and over doesn't repeat the warning
+static CASE_COLLISIONS_WARNED: RwLock<Vec<PathBuf>> = RwLock::new(Vec::new());
+
 #[derive(Debug, Clone)]
 pub struct DataPath {
     base: PathBuf,
@@ -20,14 +39,30 @@ impl DataPath {
     }
 
     pub fn default_base() -> Option<PathBuf> {
+        if let Some(root) = DEFAULT_BASE_OVERRIDE.read().unwrap().clone() {
+            return Some(root);
+        }
+
         dirs::data_local_dir().map(|pb| pb.join("notedeck"))
     }
 
+    /// Override the directory [`DataPath::default_base`] returns for the whole
+    /// process, e.g. to sandbox storage in integration tests or on platforms where
+    /// the local data dir isn't writable. `None` restores the platform default
+    pub fn set_default_base(root: Option<PathBuf>) {
+        *DEFAULT_BASE_OVERRIDE.write().unwrap() = root;
+    }
+
     pub fn default_base_or_cwd() -> PathBuf {
         use std::str::FromStr;
         Self::default_base().unwrap_or_else(|| PathBuf::from_str(".").unwrap())
     }
 
+    /// The root directory all the data paths are relative to
+    pub fn base(&self) -> &Path {
+        &self.base
+    }
+
     pub fn rel_path(&self, typ: DataPathType) -> PathBuf {
         match typ {
             DataPathType::Log => PathBuf::from("logs"),
@@ -50,6 +85,7 @@ impl Default for DataPath {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DataPathType {
     Log,
     Setting,
@@ -59,6 +95,33 @@ pub enum DataPathType {
     Cache,
 }
 
+impl DataPathType {
+    /// A stable name for the data path, e.g. for referring to it in config files
+    pub fn as_tag(&self) -> &'static str {
+        match self {
+            DataPathType::Log => "log",
+            DataPathType::Setting => "settings",
+            DataPathType::Keys => "keys",
+            DataPathType::SelectedKey => "selected_key",
+            DataPathType::Db => "db",
+            DataPathType::Cache => "cache",
+        }
+    }
+
+    /// The data path with the given [`DataPathType::as_tag`] name
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag {
+            "log" => Some(DataPathType::Log),
+            "settings" => Some(DataPathType::Setting),
+            "keys" => Some(DataPathType::Keys),
+            "selected_key" => Some(DataPathType::SelectedKey),
+            "db" => Some(DataPathType::Db),
+            "cache" => Some(DataPathType::Cache),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone)]
 pub struct Directory {
     pub file_path: PathBuf,
@@ -69,7 +132,29 @@ impl Directory {
         Self { file_path }
     }
 
-    /// Get the files in the current directory where the key is the file name and the value is the file contents
+    /// Whether the directory has been created
+    pub fn exists(&self) -> bool {
+        self.file_path.is_dir()
+    }
+
+    /// Whether the directory has no entries, e.g. to detect a first run before any
+    /// account was saved. Stops reading at the first entry. A directory that
+    /// doesn't exist yet is empty, and so is one holding only lockfiles or
+    /// staged writes
+    pub fn is_empty(&self) -> Result<bool> {
+        match fs::read_dir(&self.file_path) {
+            Ok(entries) => Ok(!entries
+                .filter_map(|entry| entry.ok())
+                .any(|entry| !is_internal_dir(&entry.file_name()))),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(true),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Get the files in the current directory where the key is the file name and the value is the file contents.
+    /// Files whose name or contents aren't valid UTF-8 are left out, see
+    /// [`Directory::get_file_names_os`] to reach them. Files differing only by case
+    /// are warned about once per directory, see [`Directory::get_case_collisions`]
     pub fn get_files(&self) -> Result<HashMap<String, String>> {
         let dir = fs::read_dir(self.file_path.clone())?;
         let map = dir
@@ -80,11 +165,51 @@ impl Directory {
                 let contents = fs::read_to_string(f.path()).ok()?;
                 Some((file_name, contents))
             })
-            .collect();
+            .collect::<HashMap<String, String>>();
+
+        let collisions = case_collisions(map.keys());
+        if !collisions.is_empty() && first_case_collision_warning(&self.file_path) {
+            tracing::warn!(
+                "files in {:?} differ only by case and may collide on case-insensitive filesystems: {:?}",
+                self.file_path,
+                collisions
+            );
+        }
 
         Ok(map)
     }
 
+    /// Like [`Directory::get_files`], but a directory which doesn't exist yet, e.g.
+    /// on first launch, is treated as empty. Other errors are still returned
+    pub fn get_files_or_empty(&self) -> Result<HashMap<String, String>> {
+        or_empty_if_missing(self.get_files())
+    }
+
+    /// Like [`Directory::get_file_names`], but a directory which doesn't exist yet
+    /// is treated as empty. Other errors are still returned
+    pub fn get_file_names_or_empty(&self) -> Result<Vec<String>> {
+        or_empty_if_missing(self.get_file_names())
+    }
+
+    /// Like [`Directory::get_files`], but also reads the files in subdirectories.
+    /// Keys are paths relative to this directory with `/` separators. As with
+    /// `get_files`, files which aren't valid UTF-8 are left out
+    pub fn get_files_recursive(&self) -> Result<HashMap<String, String>> {
+        let mut files = HashMap::new();
+        let mut visited = HashSet::new();
+        collect_files_recursive(&self.file_path, "", &mut visited, &mut files)?;
+
+        Ok(files)
+    }
+
+    /// Get groups of file names which only differ by case. On case-insensitive
+    /// filesystems (macOS and Windows by default) these would refer to the same file
+    pub fn get_case_collisions(&self) -> Result<Vec<Vec<String>>> {
+        Ok(case_collisions(&self.get_file_names()?))
+    }
+
+    /// Get the names of the files in the directory. Names which aren't valid
+    /// UTF-8 are left out, see [`Directory::get_file_names_os`]
     pub fn get_file_names(&self) -> Result<Vec<String>> {
         let dir = fs::read_dir(self.file_path.clone())?;
         let names = dir
@@ -96,41 +221,387 @@ impl Directory {
         Ok(names)
     }
 
+    /// Get a page of at most `limit` file names starting at `offset`, along with
+    /// the total number of files. Names are sorted by name so that pages stay
+    /// consistent while files are being modified
+    pub fn get_file_names_page(&self, offset: usize, limit: usize) -> Result<(Vec<String>, usize)> {
+        let mut names = self.get_file_names()?;
+        names.sort();
+
+        let total = names.len();
+        let page = names.into_iter().skip(offset).take(limit).collect();
+
+        Ok((page, total))
+    }
+
+    /// Like [`Directory::get_file_names`], but keeps names which aren't valid UTF-8
+    pub fn get_file_names_os(&self) -> Result<Vec<OsString>> {
+        let dir = fs::read_dir(self.file_path.clone())?;
+        let names = dir
+            .filter_map(|f| f.ok())
+            .filter(|f| f.path().is_file())
+            .map(|f| f.file_name())
+            .collect();
+
+        Ok(names)
+    }
+
+    /// Get the names of the files matching `pattern`, where `*` matches any run
+    /// of characters and `?` matches a single character
+    pub fn glob(&self, pattern: &str) -> Result<Vec<String>> {
+        let pattern: Vec<char> = pattern.chars().collect();
+        let mut names: Vec<String> = self
+            .get_file_names()?
+            .into_iter()
+            .filter(|name| glob_match(&pattern, &name.chars().collect::<Vec<_>>()))
+            .collect();
+        names.sort();
+
+        Ok(names)
+    }
+
     pub fn get_file(&self, file_name: String) -> Result<String> {
-        let filepath = self.file_path.clone().join(file_name.clone());
+        self.get_file_os(file_name.into())
+    }
+
+    /// Like [`Directory::get_file`], but for names which may not be valid UTF-8,
+    /// e.g. from [`Directory::get_file_names_os`]
+    pub fn get_file_os(&self, file_name: OsString) -> Result<String> {
+        let bytes = self.read_file_bytes(&file_name)?;
+        String::from_utf8(bytes)
+            .map_err(|err| Error::Io(io::Error::new(io::ErrorKind::InvalidData, err)))
+    }
+
+    /// Like [`Directory::get_file`], but fails with [`Error::FileTooLarge`] instead of
+    /// reading a file over `max_bytes`, so a huge or runaway file can't exhaust memory
+    pub fn get_file_limited(&self, file_name: String, max_bytes: u64) -> Result<String> {
+        validate_file_name(file_name.as_ref())?;
+        let filepath = self.file_path.join(&file_name);
+        if !filepath.is_file() {
+            return Err(Error::NotFound(filepath));
+        }
+
+        let file = File::open(&filepath)?;
+        if file.metadata()?.len() > max_bytes {
+            return Err(Error::FileTooLarge(filepath, max_bytes));
+        }
+
+        // the file may have grown since its size was checked
+        let mut bytes = Vec::new();
+        file.take(max_bytes + 1).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 > max_bytes {
+            return Err(Error::FileTooLarge(filepath, max_bytes));
+        }
+
+        String::from_utf8(bytes)
+            .map_err(|err| Error::Io(io::Error::new(io::ErrorKind::InvalidData, err)))
+    }
+
+    /// Get the raw contents of a file, for files which aren't valid UTF-8
+    pub fn get_file_bytes(&self, file_name: String) -> Result<Vec<u8>> {
+        self.read_file_bytes(file_name.as_ref())
+    }
+
+    fn read_file_bytes(&self, file_name: &OsStr) -> Result<Vec<u8>> {
+        validate_file_name(file_name)?;
+        let filepath = self.file_path.clone().join(file_name);
 
         if filepath.exists() && filepath.is_file() {
-            let filepath_str = filepath
-                .to_str()
-                .ok_or_else(|| Error::Generic("Could not turn path to string".to_owned()))?;
-            Ok(fs::read_to_string(filepath_str)?)
+            Ok(fs::read(filepath)?)
+        } else {
+            Err(Error::NotFound(filepath))
+        }
+    }
+
+    /// Like [`Directory::get_file`], but holds a shared lock on the file's lockfile
+    /// while reading, so it never observes a [`write_file_locked`] in progress
+    pub fn get_file_locked(&self, file_name: String) -> Result<String> {
+        validate_file_name(file_name.as_ref())?;
+        if !self.exists() {
+            return Err(Error::NotFound(self.file_path.join(&file_name)));
+        }
+
+        let lock = open_lock_file(&self.file_path, &file_name)?;
+        lock.lock_shared()?;
+
+        // the lock is released when `lock` is dropped, including on errors
+        self.get_file(file_name)
+    }
+
+    /// Read a file written by [`write_file_compressed`]. The `.gz` extension is
+    /// added to `file_name` if it doesn't already have it
+    pub fn get_file_decompressed(&self, file_name: String) -> Result<String> {
+        let bytes = self.get_file_bytes(gz_file_name(file_name))?;
+        let mut contents = String::new();
+        GzDecoder::new(bytes.as_slice()).read_to_string(&mut contents)?;
+
+        Ok(contents)
+    }
+
+    /// Read a file written by [`write_file_encrypted`] with the same `key`. Fails
+    /// if the file was tampered with or the key is wrong
+    pub fn get_file_decrypted(&self, file_name: String, key: &[u8]) -> Result<String> {
+        let cipher = encryption_cipher(key)?;
+        let bytes = self.get_file_bytes(file_name.clone())?;
+        if bytes.len() < ENCRYPTION_NONCE_LEN {
+            return Err(Error::Generic(format!(
+                "Encrypted file is too short: {file_name}"
+            )));
+        }
+
+        let (nonce, ciphertext) = bytes.split_at(ENCRYPTION_NONCE_LEN);
+        let plaintext = cipher
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                Error::Generic(format!(
+                    "Could not decrypt {file_name}: the key is wrong or the file was tampered with"
+                ))
+            })?;
+
+        String::from_utf8(plaintext)
+            .map_err(|err| Error::Io(io::Error::new(io::ErrorKind::InvalidData, err)))
+    }
+
+    /// Read a file written by [`write_file_checked`], failing with
+    /// [`Error::ChecksumMismatch`] if the contents don't match the stored checksum
+    pub fn get_file_checked(&self, file_name: String) -> Result<String> {
+        let contents = self.get_file(file_name.clone())?;
+        let expected = self.get_file(checksum_file_name(&file_name))?;
+
+        if sha256_hex(contents.as_bytes()) == expected.trim() {
+            Ok(contents)
         } else {
-            Err(Error::Io(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!("Requested file was not found: {file_name}"),
-            )))
+            Err(Error::ChecksumMismatch(file_name))
+        }
+    }
+
+    /// Read a file and parse it as json
+    pub fn read_json<T: DeserializeOwned>(&self, file_name: String) -> Result<T> {
+        Ok(serde_json::from_str(&self.get_file(file_name)?)?)
+    }
+
+    /// Like [`Directory::read_json`], but if the file has trailing garbage after a
+    /// valid json value, e.g. after a crash in the middle of a write, the leading
+    /// value is recovered instead of failing
+    pub fn read_json_lenient<T: DeserializeOwned>(&self, file_name: String) -> Result<T> {
+        let contents = self.get_file(file_name.clone())?;
+        let err = match serde_json::from_str(&contents) {
+            Ok(value) => return Ok(value),
+            Err(err) => err,
+        };
+
+        let mut values = serde_json::Deserializer::from_str(&contents).into_iter::<T>();
+        match values.next() {
+            Some(Ok(value)) => {
+                tracing::warn!(
+                    "recovered json in {file_name}, ignoring {} trailing bytes: {err}",
+                    contents.len() - values.byte_offset()
+                );
+                Ok(value)
+            }
+            _ => Err(err.into()),
         }
     }
 
     pub fn get_file_last_n_lines(&self, file_name: String, n: usize) -> Result<FileResult> {
+        self.get_file_last_n_lines_capped(file_name, n, None)
+    }
+
+    /// Like [`Directory::get_file_last_n_lines`], but if `max_bytes` is set, older lines are
+    /// dropped so the joined output never exceeds that many bytes
+    pub fn get_file_last_n_lines_capped(
+        &self,
+        file_name: String,
+        n: usize,
+        max_bytes: Option<usize>,
+    ) -> Result<FileResult> {
+        self.tail_lines(file_name, n, max_bytes, |_| true)
+    }
+
+    /// Get the last `n` lines of the file which contain `pattern`, so that a large
+    /// log can be searched without reading all of it into memory
+    pub fn get_file_last_n_matching(
+        &self,
+        file_name: String,
+        n: usize,
+        pattern: &str,
+        ignore_case: bool,
+    ) -> Result<FileResult> {
+        if ignore_case {
+            let pattern = pattern.to_lowercase();
+            self.tail_lines(file_name, n, None, |line| {
+                line.to_lowercase().contains(&pattern)
+            })
+        } else {
+            self.tail_lines(file_name, n, None, |line| line.contains(pattern))
+        }
+    }
+
+    /// Get the last `n_lines` lines of the file by reading backwards from its end, so
+    /// only the tail of a large log is read. Reading stops after `max_bytes`, in which
+    /// case fewer lines are returned and `was_truncated` is set. `total_lines_in_file`
+    /// is only known if the whole file ended up being read
+    pub fn get_file_tail_bytes(
+        &self,
+        file_name: String,
+        n_lines: usize,
+        max_bytes: usize,
+    ) -> Result<FileResult> {
+        validate_file_name(OsStr::new(&file_name))?;
+        let filepath = self.file_path.join(&file_name);
+        if !filepath.is_file() {
+            return Err(Error::NotFound(filepath));
+        }
+
+        let mut file = File::open(&filepath)?;
+        let len = file.metadata()?.len();
+        let stop = len.saturating_sub(max_bytes as u64);
+
+        let mut start = len;
+        let mut buf: Vec<u8> = Vec::new();
+        // newlines before the start of the last line; a trailing newline only ends it
+        let mut newlines = 0;
+        while start > stop && newlines < n_lines {
+            let chunk_len = TAIL_CHUNK_BYTES.min(start - stop);
+            start -= chunk_len;
+            file.seek(SeekFrom::Start(start))?;
+
+            let mut chunk = vec![0; chunk_len as usize];
+            file.read_exact(&mut chunk)?;
+            let counted = if buf.is_empty() {
+                chunk.strip_suffix(b"\n").unwrap_or(&chunk)
+            } else {
+                &chunk
+            };
+            newlines += counted.iter().filter(|&&b| b == b'\n').count();
+
+            chunk.extend_from_slice(&buf);
+            buf = chunk;
+        }
+
+        let read_whole_file = start == 0;
+        let mut data = buf.as_slice();
+        if !read_whole_file {
+            // the first line was cut off by where reading started
+            data = match data.iter().position(|&b| b == b'\n') {
+                Some(i) => &data[i + 1..],
+                None => &[],
+            };
+        }
+
+        let lines = data.lines().collect::<io::Result<Vec<String>>>()?;
+        let total_lines_in_file = read_whole_file.then_some(lines.len());
+        let lines = &lines[lines.len().saturating_sub(n_lines)..];
+
+        Ok(FileResult {
+            output: lines.join("\n"),
+            output_num_lines: lines.len(),
+            total_lines_in_file,
+            was_truncated: !read_whole_file && lines.len() < n_lines,
+        })
+    }
+
+    /// Get `count` lines of the file starting at line `start`, counting from 0, e.g.
+    /// to page through a log. Lines outside the window are only counted, not decoded
+    pub fn get_lines_range(
+        &self,
+        file_name: String,
+        start: usize,
+        count: usize,
+    ) -> Result<FileResult> {
+        validate_file_name(file_name.as_ref())?;
+        let filepath = self.file_path.join(&file_name);
+        if !filepath.is_file() {
+            return Err(Error::NotFound(filepath));
+        }
+        let mut reader = open_line_reader(&filepath)?;
+
+        let mut total_lines_in_file = 0;
+        while total_lines_in_file < start && reader.skip_until(b'\n')? > 0 {
+            total_lines_in_file += 1;
+        }
+
+        let lines = reader
+            .by_ref()
+            .lines()
+            .take(count)
+            .collect::<io::Result<Vec<String>>>()?;
+        total_lines_in_file += lines.len();
+
+        while reader.skip_until(b'\n')? > 0 {
+            total_lines_in_file += 1;
+        }
+
+        Ok(FileResult {
+            output: lines.join("\n"),
+            output_num_lines: lines.len(),
+            total_lines_in_file: Some(total_lines_in_file),
+            was_truncated: false,
+        })
+    }
+
+    /// Lazily read the lines of a file, so large logs can be processed without
+    /// holding all of them in memory. The iterator owns the open file
+    pub fn stream_lines(
+        &self,
+        file_name: String,
+    ) -> Result<impl Iterator<Item = Result<String>> + Send + 'static> {
+        validate_file_name(file_name.as_ref())?;
+        let filepath = self.file_path.join(&file_name);
+
+        if filepath.is_file() {
+            let reader = open_line_reader(&filepath)?;
+            Ok(reader.lines().map(|line| line.map_err(Error::Io)))
+        } else {
+            Err(Error::NotFound(filepath))
+        }
+    }
+
+    fn tail_lines(
+        &self,
+        file_name: String,
+        n: usize,
+        max_bytes: Option<usize>,
+        keep_line: impl Fn(&str) -> bool,
+    ) -> Result<FileResult> {
+        validate_file_name(file_name.as_ref())?;
         let filepath = self.file_path.clone().join(file_name.clone());
 
         if filepath.exists() && filepath.is_file() {
-            let file = File::open(&filepath)?;
-            let reader = io::BufReader::new(file);
+            let reader = open_line_reader(&filepath)?;
 
             let mut queue: VecDeque<String> = VecDeque::with_capacity(n);
+            // bytes of the queued lines, each counted with its joining newline
+            let mut queued_bytes = 0;
+            let mut was_truncated = false;
 
             let mut total_lines_in_file = 0;
             for line in reader.lines() {
                 let line = line?;
+                total_lines_in_file += 1;
 
+                if !keep_line(&line) {
+                    continue;
+                }
+
+                queued_bytes += line.len() + 1;
                 queue.push_back(line);
 
                 if queue.len() > n {
-                    queue.pop_front();
+                    if let Some(popped) = queue.pop_front() {
+                        queued_bytes -= popped.len() + 1;
+                    }
+                }
+
+                if let Some(max_bytes) = max_bytes {
+                    while queued_bytes.saturating_sub(1) > max_bytes {
+                        let Some(popped) = queue.pop_front() else {
+                            break;
+                        };
+                        queued_bytes -= popped.len() + 1;
+                        was_truncated = true;
+                    }
                 }
-                total_lines_in_file += 1;
             }
 
             let output_num_lines = queue.len();
@@ -138,141 +609,1828 @@ impl Directory {
             Ok(FileResult {
                 output,
                 output_num_lines,
-                total_lines_in_file,
+                total_lines_in_file: Some(total_lines_in_file),
+                was_truncated,
             })
         } else {
-            Err(Error::Generic(format!(
-                "Requested file was not found: {file_name}"
-            )))
+            Err(Error::NotFound(filepath))
+        }
+    }
+
+    /// Get the names of files whose contents duplicate another file in the directory.
+    /// Within each group of identical files the first name (sorted) is kept and the
+    /// rest are reported. Nothing is deleted.
+    pub fn get_duplicate_files(&self) -> Result<Vec<String>> {
+        self.get_duplicate_files_where(|_| true)
+    }
+
+    /// Like [`Directory::get_duplicate_files`], but only compares the files whose
+    /// name matches `pred`, e.g. to leave out files that aren't accounts
+    pub fn get_duplicate_files_where(&self, pred: impl Fn(&str) -> bool) -> Result<Vec<String>> {
+        let mut by_checksum: HashMap<String, Vec<String>> = HashMap::new();
+        for (file_name, contents) in self.get_files()? {
+            if !pred(&file_name) {
+                continue;
+            }
+
+            let checksum = sha256_hex(contents.as_bytes());
+            by_checksum.entry(checksum).or_default().push(file_name);
         }
+
+        let mut duplicates: Vec<String> = by_checksum
+            .into_values()
+            .flat_map(|mut names| {
+                names.sort();
+                names.into_iter().skip(1)
+            })
+            .collect();
+        duplicates.sort();
+
+        Ok(duplicates)
     }
 
     /// Get the file name which is most recently modified in the directory
     pub fn get_most_recent(&self) -> Result<Option<String>> {
-        let mut most_recent: Option<(SystemTime, String)> = None;
-
-        for entry in fs::read_dir(&self.file_path)? {
-            let entry = entry?;
-            let metadata = entry.metadata()?;
-            if metadata.is_file() {
-                let modified = metadata.modified()?;
-                let file_name = entry.file_name().to_string_lossy().to_string();
-
-                match most_recent {
-                    Some((last_modified, _)) if modified > last_modified => {
-                        most_recent = Some((modified, file_name));
-                    }
-                    None => {
-                        most_recent = Some((modified, file_name));
-                    }
-                    _ => {}
+        Ok(self.get_most_recent_info()?.map(|info| info.name))
+    }
+
+    /// Like [`Directory::get_most_recent`], but also returns when the file was
+    /// modified and its size
+    pub fn get_most_recent_info(&self) -> Result<Option<FileInfo>> {
+        let mut most_recent: Option<FileInfo> = None;
+
+        for info in self.get_file_infos()? {
+            match &most_recent {
+                Some(last) if info.modified > last.modified => {
+                    most_recent = Some(info);
+                }
+                None => {
+                    most_recent = Some(info);
                 }
+                _ => {}
             }
         }
 
-        Ok(most_recent.map(|(_, file_name)| file_name))
+        Ok(most_recent)
     }
-}
 
-pub struct FileResult {
-    pub output: String,
-    pub output_num_lines: usize,
-    pub total_lines_in_file: usize,
-}
+    /// Get the names of the `k` most recently modified files, newest first
+    pub fn get_recent(&self, k: usize) -> Result<Vec<String>> {
+        Ok(self
+            .get_file_infos_by_recency()?
+            .into_iter()
+            .take(k)
+            .map(|info| info.name)
+            .collect())
+    }
 
-/// Write the file to the directory
-pub fn write_file(directory: &Path, file_name: String, data: &str) -> Result<()> {
-    if !directory.exists() {
-        fs::create_dir_all(directory)?
+    fn get_file_infos_by_recency(&self) -> Result<Vec<FileInfo>> {
+        let mut infos = self.get_file_infos()?;
+        infos.sort_by_key(|info| std::cmp::Reverse(info.modified));
+
+        Ok(infos)
     }
 
-    std::fs::write(directory.join(file_name), data)?;
-    Ok(())
-}
+    /// Delete all but the `max` most recently modified files in the directory and
+    /// return the names of the deleted files. Subdirectories are left alone, and
+    /// files which disappear before they can be deleted are skipped
+    pub fn prune_to_max_files(&self, max: usize) -> Result<Vec<String>> {
+        let mut removed = Vec::new();
+        for info in self.get_file_infos_by_recency()?.into_iter().skip(max) {
+            match fs::remove_file(self.file_path.join(&info.name)) {
+                Ok(()) => removed.push(info.name),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
 
-pub fn delete_file(directory: &Path, file_name: String) -> Result<()> {
-    let file_to_delete = directory.join(file_name.clone());
-    if file_to_delete.exists() && file_to_delete.is_file() {
-        fs::remove_file(file_to_delete).map_err(Error::Io)
-    } else {
-        Err(Error::Generic(format!(
-            "Requested file to delete was not found: {file_name}"
-        )))
+        Ok(removed)
+    }
+
+    /// Delete every file in the directory matching `pred`, e.g. files older than 30
+    /// days, and return the removed names. Subdirectories are never touched, and a
+    /// file removed by someone else in the meantime is skipped
+    pub fn delete_where(&self, pred: impl Fn(&FileInfo) -> bool) -> Result<Vec<String>> {
+        let mut removed = Vec::new();
+        for info in self.get_file_infos()?.into_iter().filter(|info| pred(info)) {
+            match fs::remove_file(self.file_path.join(&info.name)) {
+                Ok(()) => removed.push(info.name),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(removed)
+    }
+
+    /// Get the name, size and modification time of every file in the directory.
+    /// Files whose metadata can't be read or whose name isn't valid UTF-8 are
+    /// skipped, as in [`Directory::get_file_names`]
+    pub fn get_file_infos(&self) -> Result<Vec<FileInfo>> {
+        let infos = fs::read_dir(&self.file_path)?
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                if !metadata.is_file() {
+                    return None;
+                }
+
+                Some(FileInfo {
+                    name: entry.file_name().into_string().ok()?,
+                    size: metadata.len(),
+                    modified: metadata.modified().ok()?,
+                })
+            })
+            .collect();
+
+        Ok(infos)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use std::path::PathBuf;
+/// How much of a file [`Directory::get_file_tail_bytes`] reads per seek
+const TAIL_CHUNK_BYTES: u64 = 8 * 1024;
 
-    use crate::{
-        storage::file_storage::{delete_file, write_file},
-        Result,
+/// Longest file name most filesystems allow, in bytes
+const MAX_FILE_NAME_LEN: usize = 255;
+
+/// Check that `file_name` names a file directly inside the directory it is joined
+/// onto, so names from elsewhere, e.g. a pubkey, can't escape it with `../`
+fn validate_file_name(file_name: &OsStr) -> Result<()> {
+    let name = file_name.to_string_lossy();
+    let invalid = if name.is_empty() {
+        Some("it is empty")
+    } else if name == "." || name == ".." {
+        Some("it refers to a directory")
+    } else if name.contains(['/', '\\']) {
+        Some("it contains a path separator")
+    } else if name.contains('\0') {
+        Some("it contains a NUL byte")
+    } else if file_name.len() > MAX_FILE_NAME_LEN {
+        Some("it is too long")
+    } else {
+        None
     };
 
-    use super::Directory;
+    match invalid {
+        Some(reason) => Err(Error::Generic(format!(
+            "Invalid file name {name:?}: {reason}"
+        ))),
+        None => Ok(()),
+    }
+}
 
-    static CREATE_TMP_DIR: fn() -> Result<PathBuf> =
-        || Ok(tempfile::TempDir::new()?.path().to_path_buf());
+fn or_empty_if_missing<T: Default>(result: Result<T>) -> Result<T> {
+    match result {
+        Err(Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(T::default()),
+        result => result,
+    }
+}
 
-    #[test]
-    fn test_add_get_delete() {
-        if let Ok(path) = CREATE_TMP_DIR() {
-            let directory = Directory::new(path);
-            let file_name = "file_test_name.txt".to_string();
-            let file_contents = "test";
-            let write_res = write_file(&directory.file_path, file_name.clone(), file_contents);
-            assert!(write_res.is_ok());
+fn open_line_reader(filepath: &Path) -> io::Result<Box<dyn BufRead + Send>> {
+    let file = File::open(filepath)?;
 
-            if let Ok(asserted_file_contents) = directory.get_file(file_name.clone()) {
-                assert_eq!(asserted_file_contents, file_contents);
-            } else {
-                panic!("File not found");
-            }
+    // rotated logs may have been compressed with write_file_compressed
+    let is_gz = filepath
+        .extension()
+        .is_some_and(|extension| extension == GZ_SUFFIX.trim_start_matches('.'));
+    if is_gz {
+        Ok(Box::new(io::BufReader::new(GzDecoder::new(file))))
+    } else {
+        Ok(Box::new(io::BufReader::new(file)))
+    }
+}
 
-            let delete_res = delete_file(&directory.file_path, file_name);
-            assert!(delete_res.is_ok());
-        } else {
-            panic!("could not get interactor")
-        }
+fn collect_files_recursive(
+    dir: &Path,
+    prefix: &str,
+    visited: &mut HashSet<PathBuf>,
+    files: &mut HashMap<String, String>,
+) -> Result<()> {
+    // symlinks can point back up the tree, so every directory is only read once
+    if !visited.insert(fs::canonicalize(dir)?) {
+        return Ok(());
     }
 
-    #[test]
-    fn test_get_multiple() {
-        if let Ok(path) = CREATE_TMP_DIR() {
-            let directory = Directory::new(path);
+    for entry in fs::read_dir(dir)?.filter_map(|entry| entry.ok()) {
+        let Ok(name) = entry.file_name().into_string() else {
+            continue;
+        };
+        if is_internal_dir(name.as_ref()) {
+            continue;
+        }
+        let path = entry.path();
+        let rel_path = format!("{prefix}{name}");
 
-            for i in 0..10 {
-                let file_name = format!("file{}.txt", i);
-                let write_res = write_file(&directory.file_path, file_name, "test");
-                assert!(write_res.is_ok());
+        if path.is_dir() {
+            collect_files_recursive(&path, &format!("{rel_path}/"), visited, files)?;
+        } else if path.is_file() {
+            if let Ok(contents) = fs::read_to_string(&path) {
+                files.insert(rel_path, contents);
             }
+        }
+    }
 
-            if let Ok(files) = directory.get_files() {
-                for i in 0..10 {
-                    let file_name = format!("file{}.txt", i);
-                    assert!(files.contains_key(&file_name));
-                    assert_eq!(files.get(&file_name).unwrap(), "test");
-                }
-            } else {
-                panic!("Files not found");
-            }
+    Ok(())
+}
 
-            if let Ok(file_names) = directory.get_file_names() {
-                for i in 0..10 {
-                    let file_name = format!("file{}.txt", i);
-                    assert!(file_names.contains(&file_name));
-                }
-            } else {
-                panic!("File names not found");
-            }
+fn glob_match(pattern: &[char], name: &[char]) -> bool {
+    let (mut p, mut n) = (0, 0);
+    // where to resume after the last `*` if the rest fails to match
+    let mut backtrack: Option<(usize, usize)> = None;
 
-            for i in 0..10 {
-                let file_name = format!("file{}.txt", i);
-                assert!(delete_file(&directory.file_path, file_name).is_ok());
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
             }
-        } else {
-            panic!("could not get interactor")
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, star_n)) => {
+                    p = star + 1;
+                    n = star_n + 1;
+                    backtrack = Some((star, star_n + 1));
+                }
+                None => return false,
+            },
         }
     }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Whether `directory` hasn't been warned about case collisions yet, marking it as
+/// warned
+fn first_case_collision_warning(directory: &Path) -> bool {
+    let mut warned = CASE_COLLISIONS_WARNED.write().unwrap();
+    if warned.iter().any(|dir| dir == directory) {
+        return false;
+    }
+
+    warned.push(directory.to_path_buf());
+    true
+}
+
+fn case_collisions<'a>(names: impl IntoIterator<Item = &'a String>) -> Vec<Vec<String>> {
+    let mut by_lowercase: HashMap<String, Vec<String>> = HashMap::new();
+    for name in names {
+        by_lowercase
+            .entry(name.to_lowercase())
+            .or_default()
+            .push(name.to_owned());
+    }
+
+    let mut collisions: Vec<Vec<String>> = by_lowercase
+        .into_values()
+        .filter(|names| names.len() > 1)
+        .map(|mut names| {
+            names.sort();
+            names
+        })
+        .collect();
+    collisions.sort();
+
+    collisions
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileInfo {
+    pub name: String,
+    /// Size in bytes
+    pub size: u64,
+    pub modified: SystemTime,
+}
+
+pub struct FileResult {
+    pub output: String,
+    pub output_num_lines: usize,
+    /// `None` if the file wasn't read in full, so its lines weren't all counted
+    pub total_lines_in_file: Option<usize>,
+    /// Whether lines within the last `n` were dropped to respect a byte cap
+    pub was_truncated: bool,
+}
+
+/// Write the file to the directory. The data is written to a temporary file in a
+/// hidden staging subdirectory first and then renamed over the destination, so a
+/// crash mid-write never leaves a truncated file behind
+pub fn write_file(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    write_file_bytes(directory, file_name, data.as_bytes())
+}
+
+/// Like [`write_file`], but for binary data
+pub fn write_file_bytes(directory: &Path, file_name: String, data: &[u8]) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+
+    if !directory.exists() {
+        fs::create_dir_all(directory)?
+    }
+
+    let tmp_path = staging_file(&staging_dir(directory)?);
+    if let Err(err) = write_synced(&tmp_path, data)
+        .and_then(|_| fs::rename(&tmp_path, directory.join(&file_name)))
+    {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err.into());
+    }
+
+    Ok(())
+}
+
+/// Write several files to the directory at once, e.g. a set of accounts. Every
+/// file is staged to a temporary file before any is renamed into place, so if one
+/// can't be staged none are replaced. The renames themselves happen one at a time:
+/// if one fails, the files renamed before it keep their new contents. The error
+/// names the file that failed
+pub fn write_files(directory: &Path, files: &[(String, &str)]) -> Result<()> {
+    let mut names = HashSet::with_capacity(files.len());
+    for (file_name, _) in files {
+        validate_file_name(file_name.as_ref())?;
+        if !names.insert(file_name) {
+            return Err(Error::Generic(format!(
+                "Could not write {file_name}: it is listed more than once"
+            )));
+        }
+    }
+
+    if !directory.exists() {
+        fs::create_dir_all(directory)?
+    }
+
+    let staging_dir = staging_dir(directory)?;
+    let mut staged = Vec::with_capacity(files.len());
+    for (file_name, data) in files {
+        let tmp_path = staging_file(&staging_dir);
+        if let Err(err) = write_synced(&tmp_path, data.as_bytes()) {
+            for tmp_path in staged.iter().chain(std::iter::once(&tmp_path)) {
+                let _ = fs::remove_file(tmp_path);
+            }
+            return Err(Error::Generic(format!(
+                "Could not write {file_name}: {err}"
+            )));
+        }
+        staged.push(tmp_path);
+    }
+
+    for ((file_name, _), tmp_path) in files.iter().zip(&staged) {
+        if let Err(err) = fs::rename(tmp_path, directory.join(file_name)) {
+            for tmp_path in &staged {
+                let _ = fs::remove_file(tmp_path);
+            }
+            return Err(Error::Generic(format!(
+                "Could not write {file_name}: {err}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Like [`write_file`], but also syncs the directory after the rename so the new
+/// file survives a power loss, for data that can't be recreated like keys
+pub fn write_file_durable(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    write_file(directory, file_name, data)?;
+    sync_directory(directory)?;
+
+    Ok(())
+}
+
+/// Flush a directory's entries, e.g. a rename into it, to disk
+#[cfg(unix)]
+fn sync_directory(directory: &Path) -> io::Result<()> {
+    File::open(directory)?.sync_all()
+}
+
+/// Windows can't open directories as files, and renames are journaled by NTFS
+#[cfg(not(unix))]
+fn sync_directory(_directory: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Append to the end of the file in the directory, creating it if it doesn't
+/// exist, e.g. for log lines
+pub fn append_file(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+    if !directory.exists() {
+        fs::create_dir_all(directory)?
+    }
+
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(directory.join(file_name))?;
+    file.write_all(data.as_bytes())?;
+    file.flush()?;
+
+    Ok(())
+}
+
+/// Name of the file in the [`DataPathType::SelectedKey`] directory holding the
+/// selected account
+pub(crate) const SELECTED_KEY_FILE_NAME: &str = "selected_pubkey";
+
+/// Read the selected account from the [`DataPathType::SelectedKey`] directory,
+/// or `None` if no account is selected
+pub fn read_selected_key(directory: &Path) -> Result<Option<String>> {
+    match Directory::new(directory.to_path_buf()).get_file(SELECTED_KEY_FILE_NAME.to_owned()) {
+        Ok(key) => Ok(Some(key)),
+        Err(Error::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Select an account in the [`DataPathType::SelectedKey`] directory. The previous
+/// selection is kept as a backup, see [`write_file_with_backup`]
+pub fn write_selected_key(directory: &Path, key: &str) -> Result<()> {
+    write_file_with_backup(directory, SELECTED_KEY_FILE_NAME.to_owned(), key)
+}
+
+/// Unselect the selected account, if any
+pub fn clear_selected_key(directory: &Path) -> Result<()> {
+    match delete_file(directory, SELECTED_KEY_FILE_NAME.to_owned()) {
+        Err(Error::NotFound(_)) => Ok(()),
+        result => result,
+    }
+}
+
+/// Like [`write_file`], but also writes the SHA-256 of the data to a
+/// `<file_name>.sha256` sidecar so [`Directory::get_file_checked`] can detect
+/// corruption
+pub fn write_file_checked(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    let checksum = sha256_hex(data.as_bytes());
+    write_file(directory, file_name.clone(), data)?;
+    write_file(directory, checksum_file_name(&file_name), &checksum)
+}
+
+fn checksum_file_name(file_name: &str) -> String {
+    format!("{file_name}.sha256")
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    sha2::Sha256::digest(data).encode_hex()
+}
+
+/// Encrypt the data with XChaCha20-Poly1305 and write it to the directory, e.g.
+/// for private keys. `key` must be 32 bytes; deriving it from a passphrase is up
+/// to the caller. The file starts with the random nonce, followed by the
+/// ciphertext and its authentication tag
+pub fn write_file_encrypted(
+    directory: &Path,
+    file_name: String,
+    data: &str,
+    key: &[u8],
+) -> Result<()> {
+    let cipher = encryption_cipher(key)?;
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, data.as_bytes())
+        .map_err(|_| Error::Generic(format!("Could not encrypt {file_name}")))?;
+
+    let mut bytes = Vec::with_capacity(nonce.len() + ciphertext.len());
+    bytes.extend_from_slice(&nonce);
+    bytes.extend_from_slice(&ciphertext);
+
+    write_file_bytes(directory, file_name, &bytes)
+}
+
+const ENCRYPTION_NONCE_LEN: usize = 24;
+
+fn encryption_cipher(key: &[u8]) -> Result<XChaCha20Poly1305> {
+    XChaCha20Poly1305::new_from_slice(key).map_err(|_| {
+        Error::Generic(format!(
+            "Encryption key must be 32 bytes, got {}",
+            key.len()
+        ))
+    })
+}
+
+/// Gzip the data and write it to the directory. The `.gz` extension is added to
+/// `file_name` if it doesn't already have it, e.g. for rotated logs
+pub fn write_file_compressed(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(data.as_bytes())?;
+
+    write_file_bytes(directory, gz_file_name(file_name), &encoder.finish()?)
+}
+
+const GZ_SUFFIX: &str = ".gz";
+
+fn gz_file_name(file_name: String) -> String {
+    if file_name.ends_with(GZ_SUFFIX) {
+        file_name
+    } else {
+        file_name + GZ_SUFFIX
+    }
+}
+
+/// Rotate the file once it grows past `max_bytes`: `file_name` becomes
+/// `file_name.1`, `file_name.1` becomes `file_name.2` and so on, and rotated
+/// files beyond `keep` are deleted. Does nothing if the file doesn't exist
+pub fn rotate_file(directory: &Path, file_name: &str, max_bytes: u64, keep: usize) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+    let path = directory.join(file_name);
+    let metadata = match fs::metadata(&path) {
+        Ok(metadata) => metadata,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(()),
+        Err(err) => return Err(err.into()),
+    };
+
+    if !metadata.is_file() || metadata.len() <= max_bytes {
+        return Ok(());
+    }
+
+    let rotated = |n: usize| directory.join(format!("{file_name}.{n}"));
+
+    if keep == 0 {
+        fs::remove_file(&path)?;
+        return Ok(());
+    }
+
+    let oldest = rotated(keep);
+    if oldest.exists() {
+        fs::remove_file(oldest)?;
+    }
+
+    for n in (1..keep).rev() {
+        let from = rotated(n);
+        if from.exists() {
+            fs::rename(from, rotated(n + 1))?;
+        }
+    }
+
+    fs::rename(path, rotated(1))?;
+
+    Ok(())
+}
+
+/// Like [`write_file`], but holds an exclusive lock on the file's lockfile while
+/// writing, so concurrent writers, e.g. two running instances, take turns instead
+/// of clobbering each other
+pub fn write_file_locked(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+    if !directory.exists() {
+        fs::create_dir_all(directory)?
+    }
+
+    let lock = open_lock_file(directory, &file_name)?;
+    lock.lock()?;
+
+    // the lock is released when `lock` is dropped, including on errors
+    write_file(directory, file_name, data)
+}
+
+/// Subdirectory holding the lockfiles of [`write_file_locked`] and
+/// [`Directory::get_file_locked`], so they never show up as files of the directory
+const LOCK_DIR_NAME: &str = ".locks";
+
+/// Open, creating it if needed, the lockfile of `file_name`. It is never written
+/// to, only locked
+fn open_lock_file(directory: &Path, file_name: &str) -> io::Result<File> {
+    let lock_dir = directory.join(LOCK_DIR_NAME);
+    fs::create_dir_all(&lock_dir)?;
+
+    fs::OpenOptions::new()
+        .read(true)
+        .append(true)
+        .create(true)
+        .open(lock_dir.join(format!("{file_name}.lock")))
+}
+
+/// Subdirectory writes are staged in before being renamed into place, so a write
+/// cut short by a crash never leaves a file among the directory's files
+const STAGING_DIR_NAME: &str = ".staging";
+
+/// Staged files untouched for this long belong to a write that never finished
+const STALE_STAGING_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Subdirectories of bookkeeping files, which are never listed as the directory's
+fn is_internal_dir(name: &OsStr) -> bool {
+    name == LOCK_DIR_NAME || name == STAGING_DIR_NAME
+}
+
+/// Create the staging directory of `directory`, removing the files left behind
+/// by interrupted writes
+fn staging_dir(directory: &Path) -> io::Result<PathBuf> {
+    let staging_dir = directory.join(STAGING_DIR_NAME);
+    fs::create_dir_all(&staging_dir)?;
+    remove_stale_staged_files(&staging_dir);
+
+    Ok(staging_dir)
+}
+
+/// A path in the staging directory no other write, in this process or another,
+/// is using, so concurrent writes of the same file never share a staging file.
+/// The name doesn't depend on the file being written, so every valid name can be
+/// staged
+fn staging_file(staging_dir: &Path) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+    staging_dir.join(format!("{}.{n}.tmp", std::process::id()))
+}
+
+fn remove_stale_staged_files(staging_dir: &Path) {
+    let Ok(entries) = fs::read_dir(staging_dir) else {
+        return;
+    };
+
+    for entry in entries.filter_map(|entry| entry.ok()) {
+        let stale = entry
+            .metadata()
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age > STALE_STAGING_AGE);
+        if stale {
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+}
+
+fn write_synced(path: &Path, data: &[u8]) -> io::Result<()> {
+    let mut file = File::create(path)?;
+    file.write_all(data)?;
+    file.sync_all()
+}
+
+pub fn delete_file(directory: &Path, file_name: String) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+    let file_to_delete = directory.join(file_name.clone());
+    if file_to_delete.exists() && file_to_delete.is_file() {
+        fs::remove_file(file_to_delete).map_err(Error::Io)
+    } else {
+        Err(Error::NotFound(file_to_delete))
+    }
+}
+
+/// Like [`write_file_durable`], but if the file already exists its previous
+/// contents are first saved to `<file_name>.bak`, so a bad write can be undone
+/// with [`restore_backup`]
+pub fn write_file_with_backup(directory: &Path, file_name: String, data: &str) -> Result<()> {
+    validate_file_name(file_name.as_ref())?;
+    let path = directory.join(&file_name);
+    if path.is_file() {
+        write_file_bytes(directory, backup_file_name(&file_name), &fs::read(path)?)?;
+    }
+
+    write_file_durable(directory, file_name, data)
+}
+
+/// Replace the file with the backup made by [`write_file_with_backup`]. The
+/// backup is kept
+pub fn restore_backup(directory: &Path, file_name: String) -> Result<()> {
+    let backup = existing_file(directory, &backup_file_name(&file_name))?;
+    write_file_bytes(directory, file_name, &fs::read(backup)?)
+}
+
+/// Name of the backup [`write_file_with_backup`] keeps of `file_name`
+pub(crate) fn backup_file_name(file_name: &str) -> String {
+    format!("{file_name}.bak")
+}
+
+/// Rename a file in the directory, replacing `to` if it exists. Both files are in
+/// the same directory, so the rename never crosses filesystems
+pub fn move_file(directory: &Path, from: String, to: String) -> Result<()> {
+    validate_file_name(to.as_ref())?;
+    let source = existing_file(directory, &from)?;
+    fs::rename(source, directory.join(to))?;
+
+    Ok(())
+}
+
+/// Copy a file in the directory, replacing `to` if it exists
+pub fn copy_file(directory: &Path, from: String, to: String) -> Result<()> {
+    validate_file_name(to.as_ref())?;
+    let source = existing_file(directory, &from)?;
+    fs::copy(source, directory.join(to))?;
+
+    Ok(())
+}
+
+fn existing_file(directory: &Path, file_name: &str) -> Result<PathBuf> {
+    validate_file_name(file_name.as_ref())?;
+    let path = directory.join(file_name);
+    if path.is_file() {
+        Ok(path)
+    } else {
+        Err(Error::NotFound(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs::File,
+        path::PathBuf,
+        time::{Duration, SystemTime},
+    };
+
+    use crate::{
+        storage::file_storage::{
+            append_file, clear_selected_key, copy_file, delete_file, move_file, read_selected_key,
+            restore_backup, rotate_file, write_file, write_file_bytes, write_file_checked,
+            write_file_compressed, write_file_durable, write_file_encrypted, write_file_locked,
+            write_file_with_backup, write_files, write_selected_key,
+        },
+        Error, Result,
+    };
+
+    use super::{first_case_collision_warning, DataPath, DataPathType, Directory};
+
+    static CREATE_TMP_DIR: fn() -> Result<PathBuf> =
+        || Ok(tempfile::TempDir::new()?.path().to_path_buf());
+
+    #[test]
+    fn test_add_get_delete() {
+        if let Ok(path) = CREATE_TMP_DIR() {
+            let directory = Directory::new(path);
+            let file_name = "file_test_name.txt".to_string();
+            let file_contents = "test";
+            let write_res = write_file(&directory.file_path, file_name.clone(), file_contents);
+            assert!(write_res.is_ok());
+
+            if let Ok(asserted_file_contents) = directory.get_file(file_name.clone()) {
+                assert_eq!(asserted_file_contents, file_contents);
+            } else {
+                panic!("File not found");
+            }
+
+            let delete_res = delete_file(&directory.file_path, file_name);
+            assert!(delete_res.is_ok());
+        } else {
+            panic!("could not get interactor")
+        }
+    }
+
+    #[test]
+    fn test_get_multiple() {
+        if let Ok(path) = CREATE_TMP_DIR() {
+            let directory = Directory::new(path);
+
+            for i in 0..10 {
+                let file_name = format!("file{}.txt", i);
+                let write_res = write_file(&directory.file_path, file_name, "test");
+                assert!(write_res.is_ok());
+            }
+
+            if let Ok(files) = directory.get_files() {
+                for i in 0..10 {
+                    let file_name = format!("file{}.txt", i);
+                    assert!(files.contains_key(&file_name));
+                    assert_eq!(files.get(&file_name).unwrap(), "test");
+                }
+            } else {
+                panic!("Files not found");
+            }
+
+            if let Ok(file_names) = directory.get_file_names() {
+                for i in 0..10 {
+                    let file_name = format!("file{}.txt", i);
+                    assert!(file_names.contains(&file_name));
+                }
+            } else {
+                panic!("File names not found");
+            }
+
+            for i in 0..10 {
+                let file_name = format!("file{}.txt", i);
+                assert!(delete_file(&directory.file_path, file_name).is_ok());
+            }
+        } else {
+            panic!("could not get interactor")
+        }
+    }
+
+    #[test]
+    fn test_write_file_atomic() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let file_name = "settings.json".to_string();
+
+        write_file(&directory.file_path, file_name.clone(), "old contents").unwrap();
+        let contents = "new".repeat(10_000);
+        write_file(&directory.file_path, file_name.clone(), &contents).unwrap();
+
+        assert_eq!(directory.get_file(file_name).unwrap(), contents);
+        assert_eq!(
+            directory.get_file_names().unwrap(),
+            vec!["settings.json".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_write_file_concurrent() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let writers: Vec<_> = ['a', 'b']
+            .into_iter()
+            .map(|c| {
+                let dir = directory.file_path.clone();
+                std::thread::spawn(move || {
+                    let contents = c.to_string().repeat(100_000);
+                    for _ in 0..20 {
+                        write_file(&dir, "settings.json".to_string(), &contents).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let contents = directory.get_file("settings.json".to_string()).unwrap();
+        assert_eq!(contents.len(), 100_000);
+        assert!(contents.chars().all(|c| c == 'a') || contents.chars().all(|c| c == 'b'));
+        assert_eq!(directory.get_file_names().unwrap(), ["settings.json"]);
+    }
+
+    #[test]
+    fn test_interrupted_write_leftovers() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let staging = directory.file_path.join(".staging");
+
+        // a write killed before its rename leaves its staged file behind
+        std::fs::create_dir_all(&staging).unwrap();
+        let stale = File::create(staging.join("1.0.tmp")).unwrap();
+        stale
+            .set_modified(SystemTime::now() - Duration::from_secs(2 * 60 * 60))
+            .unwrap();
+        std::fs::write(staging.join("2.0.tmp"), "in progress").unwrap();
+
+        assert!(directory.is_empty().unwrap());
+        assert!(directory.get_files().unwrap().is_empty());
+        assert!(directory.get_file_infos().unwrap().is_empty());
+        assert!(directory.get_files_recursive().unwrap().is_empty());
+
+        // the staging name doesn't grow with the file name
+        let long_name = "a".repeat(255);
+        write_file(&directory.file_path, long_name.clone(), "x").unwrap();
+        assert_eq!(directory.get_file_names().unwrap(), [long_name]);
+
+        // stale leftovers are swept by the next write, recent ones may be in use
+        assert!(!staging.join("1.0.tmp").exists());
+        assert!(staging.join("2.0.tmp").exists());
+    }
+
+    #[test]
+    fn test_write_files() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.join("accounts"));
+
+        let contents: Vec<String> = (0..5).map(|i| format!("account {i}")).collect();
+        let files: Vec<(String, &str)> = contents
+            .iter()
+            .enumerate()
+            .map(|(i, data)| (format!("key{i}"), data.as_str()))
+            .collect();
+        write_files(&directory.file_path, &files).unwrap();
+
+        let mut names = directory.get_file_names().unwrap();
+        names.sort();
+        assert_eq!(names, ["key0", "key1", "key2", "key3", "key4"]);
+        assert_eq!(directory.get_file("key3".to_string()).unwrap(), "account 3");
+
+        // an invalid name fails the whole batch before anything is written
+        let err = write_files(
+            &directory.file_path,
+            &[("key0".to_string(), "new"), ("../key5".to_string(), "new")],
+        );
+        assert!(matches!(err, Err(Error::Generic(msg)) if msg.contains("../key5")));
+        assert_eq!(directory.get_file("key0".to_string()).unwrap(), "account 0");
+        assert_eq!(directory.get_file_names().unwrap().len(), 5);
+
+        // so does a name listed twice
+        let err = write_files(
+            &directory.file_path,
+            &[("key0".to_string(), "new"), ("key0".to_string(), "newer")],
+        );
+        assert!(matches!(err, Err(Error::Generic(msg)) if msg.contains("key0")));
+        assert_eq!(directory.get_file("key0".to_string()).unwrap(), "account 0");
+    }
+
+    #[test]
+    fn test_write_file_durable() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let file_name = "selected_pubkey".to_string();
+
+        write_file_durable(&directory.file_path, file_name.clone(), "old").unwrap();
+        write_file_durable(&directory.file_path, file_name.clone(), "new").unwrap();
+
+        assert_eq!(directory.get_file(file_name).unwrap(), "new");
+        assert_eq!(
+            directory.get_file_names().unwrap(),
+            vec!["selected_pubkey".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_get_file_limited() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        write_file(&directory.file_path, "small".to_string(), "ok").unwrap();
+        write_file(&directory.file_path, "huge".to_string(), &"x".repeat(4096)).unwrap();
+
+        assert_eq!(
+            directory
+                .get_file_limited("small".to_string(), 1024)
+                .unwrap(),
+            "ok"
+        );
+        assert!(matches!(
+            directory.get_file_limited("huge".to_string(), 1024),
+            Err(Error::FileTooLarge(_, 1024))
+        ));
+        // exactly at the limit is fine
+        assert_eq!(
+            directory
+                .get_file_limited("huge".to_string(), 4096)
+                .unwrap()
+                .len(),
+            4096
+        );
+    }
+
+    #[test]
+    fn test_binary_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let data = [0u8, 159, 146, 150, 255];
+
+        write_file_bytes(&directory.file_path, "avatar.bin".to_string(), &data).unwrap();
+
+        assert_eq!(
+            directory.get_file_bytes("avatar.bin".to_string()).unwrap(),
+            data
+        );
+        assert!(directory.get_file("avatar.bin".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_append_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.join("logs"));
+
+        for line in ["first\n", "second\n", "third\n"] {
+            append_file(&directory.file_path, "app.log".to_string(), line).unwrap();
+        }
+
+        assert_eq!(
+            directory.get_file("app.log".to_string()).unwrap(),
+            "first\nsecond\nthird\n"
+        );
+    }
+
+    #[test]
+    fn test_rotate_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        // missing file is a no-op
+        rotate_file(dir, "app.log", 10, 2).unwrap();
+
+        write_file(dir, "app.log".to_string(), "short").unwrap();
+        rotate_file(dir, "app.log", 10, 2).unwrap();
+        assert_eq!(directory.get_file_names().unwrap(), vec!["app.log"]);
+
+        for contents in ["first log", "second log", "third log"] {
+            write_file(dir, "app.log".to_string(), contents).unwrap();
+            rotate_file(dir, "app.log", 5, 2).unwrap();
+        }
+
+        let mut names = directory.get_file_names().unwrap();
+        names.sort();
+        assert_eq!(names, vec!["app.log.1", "app.log.2"]);
+        assert_eq!(
+            directory.get_file("app.log.1".to_string()).unwrap(),
+            "third log"
+        );
+        assert_eq!(
+            directory.get_file("app.log.2".to_string()).unwrap(),
+            "second log"
+        );
+    }
+
+    #[test]
+    fn test_prune_to_max_files() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        for (i, file_name) in ["oldest", "older", "newer", "newest"].iter().enumerate() {
+            write_file(dir, file_name.to_string(), "cached").unwrap();
+            let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000 * (i as u64 + 1));
+            File::options()
+                .write(true)
+                .open(dir.join(file_name))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+        std::fs::create_dir(dir.join("subdir")).unwrap();
+
+        let mut removed = directory.prune_to_max_files(2).unwrap();
+        removed.sort();
+        assert_eq!(removed, vec!["older", "oldest"]);
+
+        let mut names = directory.get_file_names().unwrap();
+        names.sort();
+        assert_eq!(names, vec!["newer", "newest"]);
+        assert!(dir.join("subdir").is_dir());
+    }
+
+    #[test]
+    fn test_delete_where() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        let now = SystemTime::now();
+        let days = |n: u64| Duration::from_secs(n * 24 * 60 * 60);
+        for (file_name, age) in [("ancient", days(90)), ("old", days(31)), ("fresh", days(1))] {
+            write_file(dir, file_name.to_string(), "cached").unwrap();
+            File::options()
+                .write(true)
+                .open(dir.join(file_name))
+                .unwrap()
+                .set_modified(now - age)
+                .unwrap();
+        }
+        std::fs::create_dir(dir.join("old_subdir")).unwrap();
+
+        let cutoff = now - days(30);
+        let mut removed = directory
+            .delete_where(|info| info.modified < cutoff)
+            .unwrap();
+        removed.sort();
+        assert_eq!(removed, vec!["ancient", "old"]);
+
+        assert_eq!(directory.get_file_names().unwrap(), vec!["fresh"]);
+        assert!(dir.join("old_subdir").is_dir());
+    }
+
+    #[test]
+    fn test_get_file_infos() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        write_file(&directory.file_path, "small".to_string(), "abc").unwrap();
+        write_file(&directory.file_path, "big".to_string(), &"x".repeat(4096)).unwrap();
+        std::fs::create_dir(directory.file_path.join("subdir")).unwrap();
+
+        let mut infos = directory.get_file_infos().unwrap();
+        infos.sort_by_key(|info| std::cmp::Reverse(info.size));
+
+        let sizes: Vec<(&str, u64)> = infos
+            .iter()
+            .map(|info| (info.name.as_str(), info.size))
+            .collect();
+        assert_eq!(sizes, vec![("big", 4096), ("small", 3)]);
+    }
+
+    #[test]
+    fn test_last_n_matching() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let contents = [
+            "INFO starting",
+            "ERROR first",
+            "INFO working",
+            "error second",
+            "ERROR third",
+            "INFO done",
+        ]
+        .join("\n");
+        write_file(&directory.file_path, "app.log".to_string(), &contents).unwrap();
+
+        let matching = directory
+            .get_file_last_n_matching("app.log".to_string(), 2, "ERROR", false)
+            .unwrap();
+        assert_eq!(matching.output, "ERROR first\nERROR third");
+        assert_eq!(matching.output_num_lines, 2);
+        assert_eq!(matching.total_lines_in_file, Some(6));
+
+        let matching = directory
+            .get_file_last_n_matching("app.log".to_string(), 2, "ERROR", true)
+            .unwrap();
+        assert_eq!(matching.output, "error second\nERROR third");
+    }
+
+    #[test]
+    fn test_get_files_recursive() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.clone());
+
+        write_file(&path, "top.json".to_string(), "top").unwrap();
+        write_file(&path.join("accounts"), "a.json".to_string(), "a").unwrap();
+        write_file(&path.join("accounts/keys"), "b".to_string(), "b").unwrap();
+
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&path, path.join("accounts/loop")).unwrap();
+
+        let files = directory.get_files_recursive().unwrap();
+        assert_eq!(files.len(), 3);
+        assert_eq!(files["top.json"], "top");
+        assert_eq!(files["accounts/a.json"], "a");
+        assert_eq!(files["accounts/keys/b"], "b");
+    }
+
+    #[test]
+    fn test_glob() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        for file_name in [
+            "log",
+            "log.1",
+            "log.2",
+            "file1.txt",
+            "file22.txt",
+            "settings.json",
+            "a.json.bak",
+        ] {
+            write_file(&directory.file_path, file_name.to_string(), "").unwrap();
+        }
+
+        assert_eq!(directory.glob("log.*").unwrap(), vec!["log.1", "log.2"]);
+        assert_eq!(directory.glob("file?.txt").unwrap(), vec!["file1.txt"]);
+        assert_eq!(directory.glob("*.json").unwrap(), vec!["settings.json"]);
+        assert_eq!(directory.glob("*").unwrap().len(), 7);
+        assert!(directory.glob("*.ftl").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_move_and_copy_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        write_file(dir, "old_key".to_string(), "secret").unwrap();
+
+        move_file(dir, "old_key".to_string(), "new_key".to_string()).unwrap();
+        assert!(directory.get_file("old_key".to_string()).is_err());
+        assert_eq!(directory.get_file("new_key".to_string()).unwrap(), "secret");
+
+        copy_file(dir, "new_key".to_string(), "key_copy".to_string()).unwrap();
+        assert_eq!(directory.get_file("new_key".to_string()).unwrap(), "secret");
+        assert_eq!(
+            directory.get_file("key_copy".to_string()).unwrap(),
+            "secret"
+        );
+
+        assert!(move_file(dir, "missing".to_string(), "other".to_string()).is_err());
+        assert!(copy_file(dir, "missing".to_string(), "other".to_string()).is_err());
+
+        // neither end may leave the directory
+        for (from, to) in [("../new_key", "other"), ("new_key", "../new_key")] {
+            assert!(matches!(
+                move_file(dir, from.to_string(), to.to_string()),
+                Err(Error::Generic(_))
+            ));
+            assert!(matches!(
+                copy_file(dir, from.to_string(), to.to_string()),
+                Err(Error::Generic(_))
+            ));
+        }
+        assert_eq!(directory.get_file("new_key".to_string()).unwrap(), "secret");
+    }
+
+    #[test]
+    fn test_default_base_override() {
+        let root = CREATE_TMP_DIR().unwrap();
+
+        DataPath::set_default_base(Some(root.clone()));
+        let path = DataPath::default();
+        DataPath::set_default_base(None);
+
+        assert_eq!(path.base(), root);
+        assert_eq!(path.path(DataPathType::Log), root.join("logs"));
+        assert_ne!(DataPath::default_base(), Some(root));
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let path = DataPath::new("base");
+        let cache = path.path(DataPathType::Cache);
+
+        assert_eq!(cache, PathBuf::from("base").join("cache"));
+        assert!(!path.path(DataPathType::Keys).starts_with(&cache));
+        assert!(!path.path(DataPathType::Setting).starts_with(&cache));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_non_utf8_file_name() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let name = OsStr::from_bytes(b"account\xff.json").to_os_string();
+
+        std::fs::create_dir_all(&directory.file_path).unwrap();
+        std::fs::write(directory.file_path.join(&name), "key").unwrap();
+
+        assert!(directory.get_file_names().unwrap().is_empty());
+        assert!(directory.get_file_infos().unwrap().is_empty());
+        assert_eq!(directory.get_file_names_os().unwrap(), vec![name.clone()]);
+        assert_eq!(directory.get_file_os(name).unwrap(), "key");
+    }
+
+    #[test]
+    fn test_get_most_recent_info() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        std::fs::create_dir_all(dir).unwrap();
+        assert_eq!(directory.get_most_recent_info().unwrap(), None);
+
+        let newest = SystemTime::UNIX_EPOCH + Duration::from_secs(3_000);
+        for (file_name, modified) in [
+            ("old", SystemTime::UNIX_EPOCH + Duration::from_secs(1_000)),
+            ("new", newest),
+            (
+                "middle",
+                SystemTime::UNIX_EPOCH + Duration::from_secs(2_000),
+            ),
+        ] {
+            write_file(dir, file_name.to_string(), file_name).unwrap();
+            File::options()
+                .write(true)
+                .open(dir.join(file_name))
+                .unwrap()
+                .set_modified(modified)
+                .unwrap();
+        }
+
+        let info = directory.get_most_recent_info().unwrap().unwrap();
+        assert_eq!(info.name, "new");
+        assert_eq!(info.modified, newest);
+        assert_eq!(info.size, 3);
+        assert_eq!(
+            directory.get_most_recent().unwrap(),
+            Some("new".to_string())
+        );
+    }
+
+    #[test]
+    fn test_write_file_locked() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let writers: Vec<_> = ['a', 'b']
+            .into_iter()
+            .map(|c| {
+                let dir = directory.file_path.clone();
+                std::thread::spawn(move || {
+                    let contents = c.to_string().repeat(100_000);
+                    for _ in 0..20 {
+                        write_file_locked(&dir, "accounts.json".to_string(), &contents).unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for writer in writers {
+            writer.join().unwrap();
+        }
+
+        let contents = directory
+            .get_file_locked("accounts.json".to_string())
+            .unwrap();
+        assert_eq!(contents.len(), 100_000);
+        assert!(contents.chars().all(|c| c == 'a') || contents.chars().all(|c| c == 'b'));
+
+        // lockfiles stay out of the listings
+        assert_eq!(directory.get_file_names().unwrap(), ["accounts.json"]);
+        assert_eq!(directory.get_files_recursive().unwrap().len(), 1);
+        assert!(directory.get_duplicate_files().unwrap().is_empty());
+
+        let missing = Directory::new(directory.file_path.join("missing"));
+        assert!(matches!(
+            missing.get_file_locked("accounts.json".to_string()),
+            Err(Error::NotFound(_))
+        ));
+        assert!(!missing.exists());
+        assert!(matches!(
+            write_file_locked(&directory.file_path, "../accounts.json".to_string(), "x"),
+            Err(Error::Generic(_))
+        ));
+    }
+
+    #[test]
+    fn test_compressed_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let contents = (0..1000)
+            .map(|i| format!("INFO line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        write_file_compressed(&directory.file_path, "app.log".to_string(), &contents).unwrap();
+
+        assert_eq!(
+            directory
+                .get_file_decompressed("app.log".to_string())
+                .unwrap(),
+            contents
+        );
+
+        let info = &directory.get_file_infos().unwrap()[0];
+        assert_eq!(info.name, "app.log.gz");
+        assert!((info.size as usize) < contents.len());
+
+        let tail = directory
+            .get_file_last_n_lines("app.log.gz".to_string(), 2)
+            .unwrap();
+        assert_eq!(tail.output, "INFO line 998\nINFO line 999");
+        assert_eq!(tail.total_lines_in_file, Some(1000));
+    }
+
+    #[test]
+    fn test_encrypted_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let key = [7u8; 32];
+        let secret = "nsec1secretkey";
+
+        write_file_encrypted(&directory.file_path, "pubkey".to_string(), secret, &key).unwrap();
+
+        let on_disk = directory.get_file_bytes("pubkey".to_string()).unwrap();
+        assert!(!on_disk
+            .windows(secret.len())
+            .any(|window| window == secret.as_bytes()));
+
+        assert_eq!(
+            directory
+                .get_file_decrypted("pubkey".to_string(), &key)
+                .unwrap(),
+            secret
+        );
+        assert!(directory
+            .get_file_decrypted("pubkey".to_string(), &[8u8; 32])
+            .is_err());
+        assert!(directory
+            .get_file_decrypted("pubkey".to_string(), &key[..16])
+            .is_err());
+    }
+
+    #[test]
+    fn test_encrypted_file_tampered() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let key = [7u8; 32];
+
+        write_file_encrypted(&directory.file_path, "pubkey".to_string(), "secret", &key).unwrap();
+
+        let mut bytes = directory.get_file_bytes("pubkey".to_string()).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 1;
+        write_file_bytes(&directory.file_path, "pubkey".to_string(), &bytes).unwrap();
+
+        assert!(directory
+            .get_file_decrypted("pubkey".to_string(), &key)
+            .is_err());
+    }
+
+    #[test]
+    fn test_checked_file() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        write_file_checked(dir, "selected_pubkey".to_string(), "npub1abc").unwrap();
+        assert_eq!(
+            directory
+                .get_file_checked("selected_pubkey".to_string())
+                .unwrap(),
+            "npub1abc"
+        );
+
+        write_file(dir, "selected_pubkey".to_string(), "npub1abd").unwrap();
+        assert!(matches!(
+            directory.get_file_checked("selected_pubkey".to_string()),
+            Err(Error::ChecksumMismatch(name)) if name == "selected_pubkey"
+        ));
+    }
+
+    #[test]
+    fn test_stream_lines() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let contents = (0..50_000)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        write_file(&directory.file_path, "big.log".to_string(), &contents).unwrap();
+
+        let lines = directory.stream_lines("big.log".to_string()).unwrap();
+        assert_eq!(lines.count(), 50_000);
+
+        let last = directory
+            .stream_lines("big.log".to_string())
+            .unwrap()
+            .filter_map(|line| line.ok())
+            .filter(|line| line.ends_with("999"))
+            .last();
+        assert_eq!(last.as_deref(), Some("line 49999"));
+
+        assert!(directory.stream_lines("missing.log".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_missing_file_not_found() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let missing = directory.file_path.join("missing");
+
+        std::fs::create_dir_all(&directory.file_path).unwrap();
+
+        assert!(matches!(
+            directory.get_file("missing".to_string()),
+            Err(Error::NotFound(path)) if path == missing
+        ));
+        assert!(matches!(
+            directory.get_file_last_n_lines("missing".to_string(), 10),
+            Err(Error::NotFound(path)) if path == missing
+        ));
+        assert!(matches!(
+            delete_file(&directory.file_path, "missing".to_string()),
+            Err(Error::NotFound(path)) if path == missing
+        ));
+    }
+
+    #[test]
+    fn test_write_file_with_backup() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        write_file_with_backup(dir, "selected_pubkey".to_string(), "first").unwrap();
+        assert_eq!(directory.get_file_names().unwrap(), vec!["selected_pubkey"]);
+        assert!(restore_backup(dir, "selected_pubkey".to_string()).is_err());
+
+        write_file_with_backup(dir, "selected_pubkey".to_string(), "second").unwrap();
+        assert_eq!(
+            directory
+                .get_file("selected_pubkey.bak".to_string())
+                .unwrap(),
+            "first"
+        );
+        assert_eq!(
+            directory.get_file("selected_pubkey".to_string()).unwrap(),
+            "second"
+        );
+
+        restore_backup(dir, "selected_pubkey".to_string()).unwrap();
+        assert_eq!(
+            directory.get_file("selected_pubkey".to_string()).unwrap(),
+            "first"
+        );
+    }
+
+    #[test]
+    fn test_get_file_names_page() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        for i in 0..25 {
+            write_file(&directory.file_path, format!("log.{i:02}"), "").unwrap();
+        }
+
+        let mut pages = Vec::new();
+        for offset in (0..30).step_by(10) {
+            let (page, total) = directory.get_file_names_page(offset, 10).unwrap();
+            assert_eq!(total, 25);
+            pages.push(page);
+        }
+
+        assert_eq!(
+            pages.iter().map(|page| page.len()).collect::<Vec<_>>(),
+            vec![10, 10, 5]
+        );
+        assert_eq!(pages[0][0], "log.00");
+        assert_eq!(pages[1][0], "log.10");
+        assert_eq!(pages[2][4], "log.24");
+        assert!(directory.get_file_names_page(30, 10).unwrap().0.is_empty());
+    }
+
+    #[test]
+    fn test_get_recent() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+        let dir = &directory.file_path;
+
+        for (file_name, secs) in [("b", 2_000), ("d", 4_000), ("a", 1_000), ("c", 3_000)] {
+            write_file(dir, file_name.to_string(), "").unwrap();
+            File::options()
+                .write(true)
+                .open(dir.join(file_name))
+                .unwrap()
+                .set_modified(SystemTime::UNIX_EPOCH + Duration::from_secs(secs))
+                .unwrap();
+        }
+
+        assert_eq!(directory.get_recent(3).unwrap(), vec!["d", "c", "b"]);
+        assert_eq!(directory.get_recent(10).unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_missing_directory_or_empty() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.join("storage").join("accounts"));
+
+        assert!(directory.get_files().is_err());
+        assert!(directory.get_files_or_empty().unwrap().is_empty());
+        assert!(directory.get_file_names_or_empty().unwrap().is_empty());
+
+        write_file(&directory.file_path, "pubkey".to_string(), "key").unwrap();
+        assert_eq!(directory.get_file_names_or_empty().unwrap(), vec!["pubkey"]);
+    }
+
+    #[test]
+    fn test_exists_and_is_empty() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.join("accounts"));
+
+        assert!(!directory.exists());
+        assert!(directory.is_empty().unwrap());
+
+        std::fs::create_dir_all(&directory.file_path).unwrap();
+        assert!(directory.exists());
+        assert!(directory.is_empty().unwrap());
+
+        // a directory that has only been read under a lock is still empty
+        let _ = directory.get_file_locked("pubkey".to_string());
+        assert!(directory.is_empty().unwrap());
+
+        write_file(&directory.file_path, "pubkey".to_string(), "key").unwrap();
+        assert!(!directory.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_selected_key() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let dir = &path.join("selected_account");
+
+        assert_eq!(read_selected_key(dir).unwrap(), None);
+        clear_selected_key(dir).unwrap();
+
+        write_selected_key(dir, "first").unwrap();
+        write_selected_key(dir, "second").unwrap();
+        assert_eq!(read_selected_key(dir).unwrap().as_deref(), Some("second"));
+
+        clear_selected_key(dir).unwrap();
+        assert_eq!(read_selected_key(dir).unwrap(), None);
+    }
+
+    #[test]
+    fn test_file_name_validation() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path.join("keys"));
+        let dir = &directory.file_path;
+
+        for name in [
+            "../evil",
+            "..",
+            ".",
+            "",
+            "/etc/passwd",
+            "nested/file",
+            "back\\slash",
+            "nul\0byte",
+        ] {
+            assert!(write_file(dir, name.to_string(), "x").is_err(), "{name:?}");
+            assert!(directory.get_file(name.to_string()).is_err(), "{name:?}");
+            assert!(delete_file(dir, name.to_string()).is_err(), "{name:?}");
+            assert!(append_file(dir, name.to_string(), "x").is_err(), "{name:?}");
+            assert!(
+                write_file_with_backup(dir, name.to_string(), "x").is_err(),
+                "{name:?}"
+            );
+            assert!(rotate_file(dir, name, 0, 1).is_err(), "{name:?}");
+            assert!(
+                directory.get_lines_range(name.to_string(), 0, 1).is_err(),
+                "{name:?}"
+            );
+            assert!(
+                directory.stream_lines(name.to_string()).is_err(),
+                "{name:?}"
+            );
+            assert!(
+                directory
+                    .get_file_last_n_lines(name.to_string(), 1)
+                    .is_err(),
+                "{name:?}"
+            );
+        }
+        assert!(write_file(dir, "x".repeat(256), "x").is_err());
+        assert!(!path.join("evil").exists());
+
+        // appending can't create a file outside the directory either
+        assert!(matches!(
+            append_file(dir, "../escaped.log".to_string(), "x"),
+            Err(Error::Generic(_))
+        ));
+        assert!(!path.join("escaped.log").exists());
+
+        // nor can backing up a file outside of it
+        std::fs::create_dir_all(&path).unwrap();
+        std::fs::write(path.join("outside"), "secret").unwrap();
+        assert!(matches!(
+            write_file_with_backup(dir, "../outside".to_string(), "x"),
+            Err(Error::Generic(_))
+        ));
+        assert!(!path.join("outside.bak").exists());
+        assert_eq!(
+            std::fs::read_to_string(path.join("outside")).unwrap(),
+            "secret"
+        );
+
+        for name in ["pubkey", "settings.json", ".hidden", "app.log.1", "a..b"] {
+            write_file(dir, name.to_string(), "x").unwrap();
+            assert_eq!(directory.get_file(name.to_string()).unwrap(), "x");
+            delete_file(dir, name.to_string()).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_data_path_type_tags() {
+        for typ in [
+            DataPathType::Log,
+            DataPathType::Setting,
+            DataPathType::Keys,
+            DataPathType::SelectedKey,
+            DataPathType::Db,
+            DataPathType::Cache,
+        ] {
+            assert_eq!(DataPathType::from_tag(typ.as_tag()), Some(typ));
+        }
+
+        assert_eq!(DataPathType::SelectedKey.as_tag(), "selected_key");
+        assert_eq!(DataPathType::from_tag("Settings"), None);
+    }
+
+    #[test]
+    fn test_get_duplicate_files() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        write_file(&directory.file_path, "a".to_string(), "same").unwrap();
+        write_file(&directory.file_path, "b".to_string(), "same").unwrap();
+        write_file(&directory.file_path, "c".to_string(), "different").unwrap();
+
+        let duplicates = directory.get_duplicate_files().unwrap();
+        assert_eq!(duplicates, vec!["b".to_string()]);
+
+        let duplicates = directory
+            .get_duplicate_files_where(|name| name != "a")
+            .unwrap();
+        assert!(duplicates.is_empty());
+    }
+
+    #[test]
+    fn test_last_n_lines_byte_cap() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let line = "x".repeat(99);
+        let contents = vec![line.as_str(); 1000].join("\n");
+        write_file(&directory.file_path, "big.log".to_string(), &contents).unwrap();
+
+        let uncapped = directory
+            .get_file_last_n_lines("big.log".to_string(), 1000)
+            .unwrap();
+        assert_eq!(uncapped.output_num_lines, 1000);
+        assert!(!uncapped.was_truncated);
+
+        let capped = directory
+            .get_file_last_n_lines_capped("big.log".to_string(), 1000, Some(1000))
+            .unwrap();
+        assert!(capped.output.len() <= 1000);
+        assert_eq!(capped.output_num_lines, 10);
+        assert_eq!(capped.total_lines_in_file, Some(1000));
+        assert!(capped.was_truncated);
+    }
+
+    #[test]
+    fn test_get_lines_range() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let contents: String = (0..50).map(|i| format!("line {i}\n")).collect();
+        write_file(&directory.file_path, "app.log".to_string(), &contents).unwrap();
+
+        let page = directory
+            .get_lines_range("app.log".to_string(), 10, 10)
+            .unwrap();
+        let expected: Vec<String> = (10..20).map(|i| format!("line {i}")).collect();
+        assert_eq!(page.output, expected.join("\n"));
+        assert_eq!(page.output_num_lines, 10);
+        assert_eq!(page.total_lines_in_file, Some(50));
+
+        // the last page is short
+        let page = directory
+            .get_lines_range("app.log".to_string(), 45, 10)
+            .unwrap();
+        assert_eq!(page.output_num_lines, 5);
+        assert!(page.output.ends_with("line 49"));
+
+        let page = directory
+            .get_lines_range("app.log".to_string(), 60, 10)
+            .unwrap();
+        assert_eq!(page.output, "");
+        assert_eq!(page.total_lines_in_file, Some(50));
+    }
+
+    #[test]
+    fn test_get_file_tail_bytes() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        let contents: String = (0..5000).map(|i| format!("line {i}\n")).collect();
+        write_file(&directory.file_path, "big.log".to_string(), &contents).unwrap();
+
+        let naive = directory
+            .get_file_last_n_lines("big.log".to_string(), 100)
+            .unwrap();
+        let tail = directory
+            .get_file_tail_bytes("big.log".to_string(), 100, 1024 * 1024)
+            .unwrap();
+        assert_eq!(tail.output, naive.output);
+        assert_eq!(tail.output_num_lines, 100);
+        assert_eq!(tail.total_lines_in_file, None);
+        assert!(!tail.was_truncated);
+
+        // the budget runs out before 100 lines were found
+        let budgeted = directory
+            .get_file_tail_bytes("big.log".to_string(), 100, 100)
+            .unwrap();
+        assert!(budgeted.output.len() <= 100);
+        assert!(budgeted.output_num_lines < 100);
+        assert!(naive.output.ends_with(&budgeted.output));
+        assert!(budgeted.was_truncated);
+
+        // asking for more lines than the file has reads all of it
+        let whole = directory
+            .get_file_tail_bytes("big.log".to_string(), 10_000, 1024 * 1024)
+            .unwrap();
+        assert_eq!(whole.output_num_lines, 5000);
+        assert_eq!(whole.total_lines_in_file, Some(5000));
+        assert_eq!(whole.output, contents.trim_end());
+    }
+
+    // both files can only exist side by side on a case-sensitive filesystem
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    #[test]
+    fn test_case_collisions() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        write_file(&directory.file_path, "Account.txt".to_string(), "1").unwrap();
+        write_file(&directory.file_path, "account.txt".to_string(), "2").unwrap();
+        write_file(&directory.file_path, "other.txt".to_string(), "3").unwrap();
+
+        let collisions = directory.get_case_collisions().unwrap();
+        assert_eq!(
+            collisions,
+            vec![vec!["Account.txt".to_string(), "account.txt".to_string()]]
+        );
+
+        // get_files still returns both, it only warns, and only the first time
+        assert_eq!(directory.get_files().unwrap().len(), 3);
+        assert!(!first_case_collision_warning(&directory.file_path));
+        assert_eq!(directory.get_files().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_read_json_lenient() {
+        let path = CREATE_TMP_DIR().unwrap();
+        let directory = Directory::new(path);
+
+        write_file(
+            &directory.file_path,
+            "settings.json".to_string(),
+            r#"{"theme":"dark","zoom_factor":1.0}{"theme":"li"#,
+        )
+        .unwrap();
+
+        assert!(directory
+            .read_json::<serde_json::Value>("settings.json".to_string())
+            .is_err());
+
+        let value: serde_json::Value = directory
+            .read_json_lenient("settings.json".to_string())
+            .unwrap();
+        assert_eq!(value["theme"], "dark");
+        assert_eq!(value["zoom_factor"], 1.0);
+    }
 }
@@ -0,0 +1,98 @@
+//! Async versions of the file storage APIs, for reading and writing from async
+//! code without blocking the runtime. They run the sync implementations on
+//! tokio's blocking thread pool, so writes are still atomic and paths resolve
+//! the same way as the sync APIs.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use super::file_storage::{self, Directory};
+use crate::{Error, Result};
+
+async fn spawn_blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|err| Error::Generic(format!("storage task failed: {err}")))?
+}
+
+impl Directory {
+    /// Async version of [`Directory::get_file`]
+    pub async fn get_file_async(&self, file_name: String) -> Result<String> {
+        let directory = self.clone();
+        spawn_blocking(move || directory.get_file(file_name)).await
+    }
+
+    /// Async version of [`Directory::get_file_bytes`]
+    pub async fn get_file_bytes_async(&self, file_name: String) -> Result<Vec<u8>> {
+        let directory = self.clone();
+        spawn_blocking(move || directory.get_file_bytes(file_name)).await
+    }
+
+    /// Async version of [`Directory::get_files`]
+    pub async fn get_files_async(&self) -> Result<HashMap<String, String>> {
+        let directory = self.clone();
+        spawn_blocking(move || directory.get_files()).await
+    }
+
+    /// Async version of [`Directory::get_file_names`]
+    pub async fn get_file_names_async(&self) -> Result<Vec<String>> {
+        let directory = self.clone();
+        spawn_blocking(move || directory.get_file_names()).await
+    }
+}
+
+/// Async version of [`file_storage::write_file`]
+pub async fn write_file_async(directory: PathBuf, file_name: String, data: String) -> Result<()> {
+    spawn_blocking(move || file_storage::write_file(&directory, file_name, &data)).await
+}
+
+/// Async version of [`file_storage::write_file_bytes`]
+pub async fn write_file_bytes_async(
+    directory: PathBuf,
+    file_name: String,
+    data: Vec<u8>,
+) -> Result<()> {
+    spawn_blocking(move || file_storage::write_file_bytes(&directory, file_name, &data)).await
+}
+
+/// Async version of [`file_storage::delete_file`]
+pub async fn delete_file_async(directory: PathBuf, file_name: String) -> Result<()> {
+    spawn_blocking(move || file_storage::delete_file(&directory, file_name)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_async_round_trip() {
+        let path = tempfile::TempDir::new().unwrap();
+        let directory = Directory::new(path.path().join("settings"));
+
+        write_file_async(
+            directory.file_path.clone(),
+            "settings.json".to_string(),
+            "{}".to_string(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            directory
+                .get_file_async("settings.json".to_string())
+                .await
+                .unwrap(),
+            "{}"
+        );
+        assert_eq!(
+            directory.get_file_names_async().await.unwrap(),
+            vec!["settings.json".to_string()]
+        );
+
+        delete_file_async(directory.file_path.clone(), "settings.json".to_string())
+            .await
+            .unwrap();
+        assert!(directory.get_files_async().await.unwrap().is_empty());
+    }
+}
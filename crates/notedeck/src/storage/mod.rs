@@ -1,5 +1,24 @@
 mod account_storage;
+mod archive;
 mod file_storage;
+#[cfg(feature = "async-storage")]
+mod file_storage_async;
+mod settings_store;
+mod watch;
 
-pub use account_storage::{AccountStorage, AccountStorageReader, AccountStorageWriter};
-pub use file_storage::{delete_file, write_file, DataPath, DataPathType, Directory};
+pub use account_storage::{
+    dedupe_accounts, read_selected_key_healed, AccountStorage, AccountStorageReader,
+    AccountStorageWriter,
+};
+pub use archive::{export_archive, import_archive, ArchiveFormat};
+pub use file_storage::{
+    append_file, clear_selected_key, copy_file, delete_file, move_file, read_selected_key,
+    restore_backup, rotate_file, write_file, write_file_bytes, write_file_checked,
+    write_file_compressed, write_file_durable, write_file_encrypted, write_file_locked,
+    write_file_with_backup, write_files, write_selected_key, DataPath, DataPathType, Directory,
+    FileInfo,
+};
+#[cfg(feature = "async-storage")]
+pub use file_storage_async::{delete_file_async, write_file_async, write_file_bytes_async};
+pub use settings_store::SettingsStore;
+pub use watch::DirectoryEvent;
@@ -0,0 +1,73 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::file_storage::{write_file, DataPath, DataPathType, Directory};
+use crate::Result;
+
+/// Stores each setting as json in its own `<key>.json` file in the settings
+/// directory. Writes are atomic, see [`write_file`]
+#[derive(Debug, Clone)]
+pub struct SettingsStore {
+    directory: Directory,
+}
+
+impl SettingsStore {
+    pub fn new(path: &DataPath) -> Self {
+        Self {
+            directory: Directory::new(path.path(DataPathType::Setting)),
+        }
+    }
+
+    /// Read a setting. Fails with [`crate::Error::NotFound`] if it was never set
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Result<T> {
+        self.directory.read_json(file_name(key))
+    }
+
+    pub fn set<T: Serialize>(&self, key: &str, value: &T) -> Result<()> {
+        write_file(
+            &self.directory.file_path,
+            file_name(key),
+            &serde_json::to_string(value)?,
+        )
+    }
+}
+
+fn file_name(key: &str) -> String {
+    format!("{key}.json")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::Error;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Columns {
+        widths: Vec<f32>,
+        compact: bool,
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let path = DataPath::new(tempfile::TempDir::new().unwrap().path());
+        let settings = SettingsStore::new(&path);
+
+        assert!(matches!(
+            settings.get::<Columns>("columns"),
+            Err(Error::NotFound(_))
+        ));
+
+        let columns = Columns {
+            widths: vec![300.0, 450.5],
+            compact: true,
+        };
+        settings.set("columns", &columns).unwrap();
+
+        assert_eq!(settings.get::<Columns>("columns").unwrap(), columns);
+        assert!(path
+            .path(DataPathType::Setting)
+            .join("columns.json")
+            .is_file());
+    }
+}
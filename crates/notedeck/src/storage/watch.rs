@@ -0,0 +1,116 @@
+use std::sync::mpsc::{self, Receiver};
+
+use notify::{
+    event::{ModifyKind, RenameMode},
+    EventKind, RecursiveMode, Watcher,
+};
+
+use super::file_storage::Directory;
+use crate::{Error, Result};
+
+/// A change to a file in a watched [`Directory`], made by this or another process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DirectoryEvent {
+    Created(String),
+    Modified(String),
+    Removed(String),
+}
+
+impl Directory {
+    /// Watch the directory for files being created, modified or removed, e.g. by
+    /// a second running instance. Subdirectories aren't watched. The watcher
+    /// stops at the first change after the receiver is dropped
+    pub fn watch(&self) -> Result<Receiver<DirectoryEvent>> {
+        let (notify_tx, notify_rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(notify_tx).map_err(|err| {
+            Error::Generic(format!("Could not watch {:?}: {err}", self.file_path))
+        })?;
+        watcher
+            .watch(&self.file_path, RecursiveMode::NonRecursive)
+            .map_err(|err| {
+                Error::Generic(format!("Could not watch {:?}: {err}", self.file_path))
+            })?;
+
+        let (tx, rx) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("directory-watcher".to_owned())
+            .spawn(move || {
+                // keep the watcher alive for as long as someone is listening
+                let _watcher = watcher;
+
+                for event in notify_rx {
+                    let event = match event {
+                        Ok(event) => event,
+                        Err(err) => {
+                            tracing::error!("directory watcher error: {err}");
+                            continue;
+                        }
+                    };
+
+                    for event in directory_events(event) {
+                        if tx.send(event).is_err() {
+                            return;
+                        }
+                    }
+                }
+            })?;
+
+        Ok(rx)
+    }
+}
+
+fn directory_events(event: notify::Event) -> Vec<DirectoryEvent> {
+    let names = event.paths.iter().filter_map(|path| {
+        path.file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+    });
+
+    // files are written through a temporary file which is renamed over the
+    // destination, so renames are reported as the files they remove and create
+    match event.kind {
+        EventKind::Create(_) | EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+            names.map(DirectoryEvent::Created).collect()
+        }
+        EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(RenameMode::From)) => {
+            names.map(DirectoryEvent::Removed).collect()
+        }
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => names
+            .enumerate()
+            .map(|(i, name)| {
+                if i == 0 {
+                    DirectoryEvent::Removed(name)
+                } else {
+                    DirectoryEvent::Created(name)
+                }
+            })
+            .collect(),
+        EventKind::Modify(_) => names.map(DirectoryEvent::Modified).collect(),
+        _ => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::storage::write_file;
+
+    #[test]
+    fn test_watch_created() {
+        let path = tempfile::TempDir::new().unwrap();
+        let directory = Directory::new(path.path().to_path_buf());
+        let events = directory.watch().unwrap();
+
+        write_file(&directory.file_path, "accounts.json".to_string(), "{}").unwrap();
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let created = std::iter::from_fn(|| {
+            events
+                .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+                .ok()
+        })
+        .any(|event| event == DirectoryEvent::Created("accounts.json".to_string()));
+        assert!(created);
+    }
+}
@@ -28,6 +28,7 @@ impl Support {
 }
 
 static MAX_LOG_LINES: usize = 500;
+static MAX_LOG_BYTES: usize = 256 * 1024;
 pub static SUPPORT_EMAIL: &str = "support+notedeck@damus.io";
 static EMAIL_TEMPLATE: &str = concat!("version ", env!("CARGO_PKG_VERSION"), "\nCommit hash: ", env!("GIT_COMMIT_HASH"), "\n\nDescribe the bug you have encountered:\n<-- your statement here -->\n\n===== Paste your log below =====\n\n");
 
@@ -52,7 +53,11 @@ impl Support {
 fn get_log_str(interactor: &Directory) -> Option<String> {
     match interactor.get_most_recent() {
         Ok(Some(most_recent_name)) => {
-            match interactor.get_file_last_n_lines(most_recent_name.clone(), MAX_LOG_LINES) {
+            match interactor.get_file_last_n_lines_capped(
+                most_recent_name.clone(),
+                MAX_LOG_LINES,
+                Some(MAX_LOG_BYTES),
+            ) {
                 Ok(file_output) => {
                     return Some(
                         get_prefix(
@@ -81,10 +86,15 @@ fn get_log_str(interactor: &Directory) -> Option<String> {
     None
 }
 
-fn get_prefix(file_name: &str, lines_displayed: usize, num_total_lines: usize) -> String {
-    format!(
-        "===\nDisplaying the last {lines_displayed} of {num_total_lines} lines in file {file_name}\n===\n\n",
-    )
+fn get_prefix(file_name: &str, lines_displayed: usize, num_total_lines: Option<usize>) -> String {
+    match num_total_lines {
+        Some(num_total_lines) => format!(
+            "===\nDisplaying the last {lines_displayed} of {num_total_lines} lines in file {file_name}\n===\n\n",
+        ),
+        None => format!(
+            "===\nDisplaying the last {lines_displayed} lines in file {file_name}\n===\n\n",
+        ),
+    }
 }
 
 struct MailtoBuilder {
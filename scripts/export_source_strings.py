@@ -275,9 +275,12 @@ def escape_rust_placeholders(text: str) -> str:
     # Convert Rust placeholders to Fluent placeholders
     return re.sub(r'\{([a-zA-Z][a-zA-Z0-9_]*)\}', r'{$\1}', text)
 
+# Bytes of the comment hash appended to keys - matches KEY_HASH_BYTES in Rust
+KEY_HASH_BYTES = 3
+
 def simple_hash(s: str) -> str:
-    """Simple hash function using MD5 - matches Rust implementation, 4 hex chars"""
-    return hashlib.md5(s.encode('utf-8')).hexdigest()[:4]
+    """Simple hash function using MD5 - matches Rust implementation, 2 hex chars per byte"""
+    return hashlib.md5(s.encode('utf-8')).hexdigest()[:KEY_HASH_BYTES * 2]
 
 def normalize_key(message, comment=None):
     """Normalize a message to create a consistent key - matches Rust normalize_ftl_key function"""